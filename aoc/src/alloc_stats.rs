@@ -0,0 +1,153 @@
+use std::fmt;
+
+/// Allocations made while running a phase, on the thread running it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations and reallocations.
+    pub allocations: u64,
+    /// Bytes requested by the allocations and reallocations.
+    pub bytes: u64,
+    /// Highest memory in use on top of what was in use when the phase started.
+    pub peak: u64,
+}
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Runs `f` and counts its allocations, `None` without the `alloc-stats` feature.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (value, stats) = counting::measure(f);
+        (value, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+/// Global allocator forwarding to the system one and counting, per thread, what goes through it.
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+        static BYTES: Cell<u64> = const { Cell::new(0) };
+        // Memory freed on another thread than the one that allocated it can make it negative
+        static IN_USE: Cell<i64> = const { Cell::new(0) };
+        static PEAK: Cell<i64> = const { Cell::new(0) };
+    }
+
+    fn record(allocated: usize, freed: usize) {
+        if allocated > 0 {
+            ALLOCATIONS.with(|count| count.set(count.get() + 1));
+            BYTES.with(|bytes| bytes.set(bytes.get() + allocated as u64));
+        }
+        let in_use = IN_USE.with(|in_use| {
+            in_use.set(in_use.get() + allocated as i64 - freed as i64);
+            in_use.get()
+        });
+        PEAK.with(|peak| peak.set(peak.get().max(in_use)));
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record(layout.size(), 0);
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            record(layout.size(), 0);
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            record(0, layout.size());
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record(new_size, layout.size());
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+        let allocations = ALLOCATIONS.with(Cell::get);
+        let bytes = BYTES.with(Cell::get);
+        let in_use = IN_USE.with(Cell::get);
+        PEAK.with(|peak| peak.set(in_use));
+        let value = f();
+        let stats = AllocStats {
+            allocations: ALLOCATIONS.with(Cell::get) - allocations,
+            bytes: BYTES.with(Cell::get) - bytes,
+            peak: (PEAK.with(Cell::get) - in_use) as u64,
+        };
+        (value, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests_alloc_stats {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn test_measure() {
+        let (value, stats) = measure(|| {
+            let small = Vec::<u8>::with_capacity(100);
+            let large = Vec::<u8>::with_capacity(10_000);
+            small.capacity() + large.capacity()
+        });
+        assert_eq!(value, 10_100);
+        if cfg!(feature = "alloc-stats") {
+            let stats = stats.unwrap();
+            assert_eq!(stats.allocations, 2);
+            assert_eq!(stats.bytes, 10_100);
+            assert_eq!(stats.peak, 10_100);
+
+            let (_, stats) = measure(|| 1 + 1);
+            assert_eq!(stats, Some(AllocStats::default()));
+        } else {
+            assert_eq!(stats, None);
+        }
+    }
+}
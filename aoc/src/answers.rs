@@ -0,0 +1,8 @@
+use std::path::PathBuf;
+
+use crate::inputs_dir;
+
+/// Path of the answers manifest, next to the inputs.
+pub fn manifest_path() -> PathBuf {
+    inputs_dir().join("answers.toml")
+}
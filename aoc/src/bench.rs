@@ -0,0 +1,248 @@
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+
+use common::Part;
+
+use crate::alloc_stats::{self, AllocStats};
+use crate::{days, default_input_path, parse_day, read_input};
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+impl Phase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part_1",
+            Phase::Part2 => "part_2",
+        }
+    }
+}
+
+/// Timings of one phase of a day, one sample per iteration.
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub samples: Vec<Duration>,
+    /// Allocations of the first iteration, with the `alloc-stats` feature.
+    pub allocations: Option<AllocStats>,
+}
+impl Measurement {
+    pub fn min(&self) -> Duration {
+        self.samples.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().copied().max().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut samples = self.samples.clone();
+        samples.sort();
+        match samples.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 0 => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+            len => samples[len / 2],
+        }
+    }
+}
+
+/// `aoc bench [<day>...] [--iterations N] [--json PATH] [--csv PATH]`
+pub fn bench(args: &[String]) -> Result<(), String> {
+    let mut selected_days = Vec::new();
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut json_path = None;
+    let mut csv_path = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let value = args.next().ok_or("missing value for `--iterations`")?;
+                iterations = match value.parse() {
+                    Ok(iterations @ 1..) => iterations,
+                    _ => {
+                        return Err(format!(
+                            "invalid iterations `{value}`, expected a positive number"
+                        ))
+                    }
+                };
+            }
+            "--json" => json_path = Some(args.next().ok_or("missing value for `--json`")?),
+            "--csv" => csv_path = Some(args.next().ok_or("missing value for `--csv`")?),
+            _ => selected_days.push(parse_day(arg)?),
+        }
+    }
+    let explicit_days = !selected_days.is_empty();
+    if !explicit_days {
+        selected_days = (1..=25).collect();
+    }
+
+    let mut measurements = Vec::new();
+    for day in selected_days {
+        let Some(solution) = days::solution(day) else {
+            if explicit_days {
+                return Err(format!("day {day} is not solved yet"));
+            }
+            continue;
+        };
+        let path = default_input_path(day);
+        if !path.exists() {
+            if explicit_days {
+                return Err(format!("missing input `{}`", path.display()));
+            }
+            eprintln!("Day {day:02}: skipped, no input at `{}`", path.display());
+            continue;
+        }
+        let input = read_input(path)?;
+        if let Err(err) = solution.parse_dyn(&input) {
+            let err = format!("invalid input for day {day}: {err}");
+            if explicit_days {
+                return Err(err);
+            }
+            eprintln!("Day {day:02}: skipped, {err}");
+            continue;
+        }
+
+        let mut parse = Measurement {
+            day,
+            phase: Phase::Parse,
+            samples: Vec::with_capacity(iterations),
+            allocations: None,
+        };
+        let mut parts = [Phase::Part1, Phase::Part2].map(|phase| Measurement {
+            day,
+            phase,
+            samples: Vec::with_capacity(iterations),
+            allocations: None,
+        });
+        for _ in 0..iterations {
+            let now = Instant::now();
+            let (parsed, stats) = alloc_stats::measure(|| solution.parse_dyn(&input));
+            parse.samples.push(now.elapsed());
+            parse.allocations = parse.allocations.or(stats);
+            let parsed = parsed.expect("input was parsed before");
+
+            for (measurement, part) in parts.iter_mut().zip([Part::One, Part::Two]) {
+                let now = Instant::now();
+                let (answer, stats) =
+                    alloc_stats::measure(|| solution.part_dyn(parsed.as_ref(), part));
+                std::hint::black_box(answer);
+                measurement.samples.push(now.elapsed());
+                measurement.allocations = measurement.allocations.or(stats);
+            }
+        }
+        for measurement in std::iter::once(parse).chain(parts) {
+            let stats = measurement
+                .allocations
+                .map_or(String::new(), |stats| format!("  {stats}"));
+            println!(
+                "Day {:02} {:<6}  min {:>10.2?}  median {:>10.2?}  max {:>10.2?}{stats}",
+                measurement.day,
+                measurement.phase.name(),
+                measurement.min(),
+                measurement.median(),
+                measurement.max()
+            );
+            measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = json_path {
+        write_report(path, &to_json(&measurements, iterations))?;
+    }
+    if let Some(path) = csv_path {
+        write_report(path, &to_csv(&measurements, iterations))?;
+    }
+    Ok(())
+}
+
+fn write_report(path: &str, report: &str) -> Result<(), String> {
+    std::fs::write(path, report).map_err(|err| format!("could not write `{path}`: {err}"))
+}
+
+/// Report with the durations in nanoseconds.
+pub fn to_json(measurements: &[Measurement], iterations: usize) -> String {
+    let mut json = format!("{{\n  \"iterations\": {iterations},\n  \"results\": [");
+    for (i, measurement) in measurements.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        write!(
+            json,
+            "\n    {{\"day\": {}, \"phase\": \"{}\", \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+            measurement.day,
+            measurement.phase.name(),
+            measurement.min().as_nanos(),
+            measurement.median().as_nanos(),
+            measurement.max().as_nanos()
+        )
+        .unwrap();
+    }
+    json.push_str("\n  ]\n}\n");
+    json
+}
+
+/// Report with the durations in nanoseconds.
+pub fn to_csv(measurements: &[Measurement], iterations: usize) -> String {
+    let mut csv = String::from("day,phase,iterations,min_ns,median_ns,max_ns\n");
+    for measurement in measurements {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            measurement.day,
+            measurement.phase.name(),
+            iterations,
+            measurement.min().as_nanos(),
+            measurement.median().as_nanos(),
+            measurement.max().as_nanos()
+        )
+        .unwrap();
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests_bench {
+    use super::*;
+
+    fn measurement(samples: &[u64]) -> Measurement {
+        Measurement {
+            day: 3,
+            phase: Phase::Part1,
+            samples: samples.iter().map(|ns| Duration::from_nanos(*ns)).collect(),
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn test_statistics() {
+        let odd = measurement(&[30, 10, 20]);
+        assert_eq!(odd.min(), Duration::from_nanos(10));
+        assert_eq!(odd.median(), Duration::from_nanos(20));
+        assert_eq!(odd.max(), Duration::from_nanos(30));
+        assert_eq!(
+            measurement(&[40, 10, 30, 20]).median(),
+            Duration::from_nanos(25)
+        );
+    }
+
+    #[test]
+    fn test_reports() {
+        let measurements = [measurement(&[30, 10, 20])];
+        assert_eq!(
+            to_csv(&measurements, 3),
+            "day,phase,iterations,min_ns,median_ns,max_ns\n3,part_1,3,10,20,30\n"
+        );
+        assert_eq!(
+            to_json(&measurements, 3),
+            "{\n  \"iterations\": 3,\n  \"results\": [\n    {\"day\": 3, \"phase\": \"part_1\", \"min_ns\": 10, \"median_ns\": 20, \"max_ns\": 30}\n  ]\n}\n"
+        );
+    }
+}
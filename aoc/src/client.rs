@@ -0,0 +1,342 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use common::{Answer, Part};
+
+use crate::history::{self, History, Submission};
+use crate::{days, default_input_path, parse_day, read_input};
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2023;
+const USER_AGENT: &str = "github.com/Captainfl4me/aoc-2023 runner";
+
+/// Outcome of an answer submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Incorrect,
+    /// Another answer was submitted too recently.
+    Wait(Duration),
+    /// The part is already solved, or part 2 is submitted before part 1.
+    WrongLevel,
+}
+impl Verdict {
+    /// Reads the verdict from the page returned after submitting an answer.
+    pub fn parse(page: &str) -> Result<Self, String> {
+        if page.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            Ok(if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Incorrect
+            })
+        } else if page.contains("You gave an answer too recently") {
+            let left = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(left, _)| left)
+                .ok_or("could not read the time left to wait")?;
+            parse_wait(left)
+                .map(Verdict::Wait)
+                .ok_or(format!("invalid time left to wait `{left}`"))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::WrongLevel)
+        } else {
+            Err("unexpected answer page".to_string())
+        }
+    }
+}
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "the right answer"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "not the right answer"),
+            Verdict::Wait(left) => write!(f, "not checked, wait {}s", left.as_secs()),
+            Verdict::WrongLevel => write!(f, "not checked, the part is locked or already solved"),
+        }
+    }
+}
+
+/// `1m 5s`, `42s` or `1h 2m 3s`.
+fn parse_wait(left: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for amount in left.split_whitespace() {
+        let unit = match amount.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds += amount[..amount.len() - 1].parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Client of the puzzle website, authenticated with the session cookie of a logged in browser.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Client of `AOC_URL` (default: the real website) with the session in `AOC_SESSION`.
+    pub fn from_env() -> Result<Self, String> {
+        let session = std::env::var("AOC_SESSION")
+            .map_err(|_| "set `AOC_SESSION` to the session cookie of the website")?;
+        let base_url = std::env::var("AOC_URL").unwrap_or(DEFAULT_URL.to_string());
+        Ok(Self::new(&base_url, session.trim()))
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, String> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        read_response(&url, self.request("GET", &url).call())
+    }
+
+    /// Downloads the input of `day` to `path` unless it is already there, returns whether it
+    /// was downloaded. Empty files are placeholders and get downloaded.
+    pub fn download_input(&self, day: u8, path: &Path, force: bool) -> Result<bool, String> {
+        let cached = std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0);
+        if cached && !force {
+            return Ok(false);
+        }
+        let input = self.fetch_input(day)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("could not create `{}`: {err}", dir.display()))?;
+        }
+        std::fs::write(path, input)
+            .map_err(|err| format!("could not write `{}`: {err}", path.display()))?;
+        Ok(true)
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, String> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &level), ("answer", answer)]);
+        Verdict::parse(&read_response(&url, response)?)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| format!("could not read the response of `{url}`: {err}")),
+        Err(ureq::Error::Status(400, _)) => Err(format!(
+            "`{url}` rejected the session, is `AOC_SESSION` up to date?"
+        )),
+        Err(ureq::Error::Status(404, _)) => Err(format!(
+            "`{url}` was not found, is the puzzle unlocked yet?"
+        )),
+        Err(err) => Err(format!("request to `{url}` failed: {err}")),
+    }
+}
+
+/// `aoc download <day>... [--force]`
+pub fn download(args: &[String]) -> Result<(), String> {
+    let mut selected_days = Vec::new();
+    let mut force = false;
+    for arg in args {
+        match arg.as_str() {
+            "--force" => force = true,
+            _ => selected_days.push(parse_day(arg)?),
+        }
+    }
+    if selected_days.is_empty() {
+        return Err("missing day".to_string());
+    }
+
+    let client = Client::from_env()?;
+    for day in selected_days {
+        let path = default_input_path(day);
+        if client.download_input(day, &path, force)? {
+            println!("Day {day:02}: downloaded to `{}`", path.display());
+        } else {
+            println!("Day {day:02}: already in `{}`", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// `aoc submit <day> <1|2> [<answer>] [--force]`, the answer defaults to the one of the
+/// solution. Answers the submission log already proves wrong are not sent unless forced.
+pub fn submit(args: &[String]) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut force = false;
+    for arg in args {
+        match arg.as_str() {
+            "--force" => force = true,
+            _ => positional.push(arg),
+        }
+    }
+    let [day, part, rest @ ..] = positional.as_slice() else {
+        return Err("expected a day and a part".to_string());
+    };
+    let day = parse_day(day)?;
+    let part = match part.as_str() {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return Err(format!("invalid part `{part}`, expected 1 or 2")),
+    };
+    let answer = match rest {
+        [] => solve(day, part)?,
+        [answer] => answer.trim().to_string(),
+        [_, arg, ..] => return Err(format!("unexpected argument `{arg}`")),
+    };
+    if answer.is_empty() || answer.contains(['\t', '\n']) {
+        return Err(format!("invalid answer `{answer}`"));
+    }
+
+    let path = history::log_path(day);
+    let mut history = History::load(&path)?;
+    if !force {
+        history
+            .check(part, &answer)
+            .map_err(|reason| format!("{reason}, use `--force` to submit it anyway"))?;
+    }
+    let verdict = Client::from_env()?.submit(day, part, &answer)?;
+    println!("Day {day:02} part {}: {answer} is {verdict}", part.number());
+    history.append(&path, Submission::now(part, &answer, verdict))
+}
+
+/// Answer of the solution on the default input.
+fn solve(day: u8, part: Part) -> Result<String, String> {
+    let solution = days::solution(day).ok_or(format!("day {day} is not solved yet"))?;
+    let input = read_input(default_input_path(day))?;
+    let parsed = solution
+        .parse_dyn(&input)
+        .map_err(|err| format!("invalid input: {}", err.diagnostic(&input)))?;
+    match solution.part_dyn(parsed.as_ref(), part) {
+        Answer::None => Err(format!("day {day} part {} has no puzzle", part.number())),
+        answer => Ok(answer.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests_client {
+    use super::*;
+    use mock_server::MockServer;
+
+    #[test]
+    fn test_verdict_parse() {
+        let page = |message: &str| format!("<main>\n<article><p>{message}</p></article>\n</main>");
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer!  You are one gold star closer."
+            )),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low.  Please wait one minute."
+            )),
+            Ok(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer.  If you're stuck, ...")),
+            Ok(Verdict::Incorrect)
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 12s left to wait."
+            )),
+            Ok(Verdict::Wait(Duration::from_secs(72)))
+        );
+        assert_eq!(
+            Verdict::parse(&page("You don't seem to be solving the right level.")),
+            Ok(Verdict::WrongLevel)
+        );
+        assert!(Verdict::parse("<html></html>").is_err());
+        assert_eq!(parse_wait("42s"), Some(Duration::from_secs(42)));
+        assert_eq!(parse_wait("1h 2m 3s"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse_wait("soon"), None);
+    }
+
+    #[test]
+    fn test_download_input() {
+        let address = MockServer::new("secret")
+            .with_input(1, "1abc2\npqr3stu8vwx\n")
+            .spawn()
+            .unwrap();
+        let url = format!("http://{address}");
+        let path = std::env::temp_dir()
+            .join(format!("aoc-client-{}", std::process::id()))
+            .join("day-01")
+            .join("input.txt");
+
+        let client = Client::new(&url, "secret");
+        assert_eq!(client.download_input(1, &path, false), Ok(true));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "1abc2\npqr3stu8vwx\n"
+        );
+        // Cached, the server is not asked again
+        let rejected = Client::new(&url, "expired");
+        assert_eq!(rejected.download_input(1, &path, false), Ok(false));
+        assert!(rejected
+            .download_input(1, &path, true)
+            .unwrap_err()
+            .contains("rejected the session"));
+        assert!(client.fetch_input(2).unwrap_err().contains("not found"));
+        std::fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let address = MockServer::new("secret")
+            .with_answer(1, 1, "142")
+            .with_answer(1, 2, "281")
+            .with_cooldown(Duration::ZERO)
+            .spawn()
+            .unwrap();
+        let client = Client::new(&format!("http://{address}"), "secret");
+        assert_eq!(client.submit(1, Part::Two, "281"), Ok(Verdict::WrongLevel));
+        assert_eq!(client.submit(1, Part::One, "150"), Ok(Verdict::TooHigh));
+        assert_eq!(client.submit(1, Part::One, "99"), Ok(Verdict::TooLow));
+        assert_eq!(client.submit(1, Part::One, "142"), Ok(Verdict::Correct));
+        assert_eq!(client.submit(1, Part::Two, "281"), Ok(Verdict::Correct));
+
+        let address = MockServer::new("secret")
+            .with_answer(1, 1, "142")
+            .spawn()
+            .unwrap();
+        let client = Client::new(&format!("http://{address}"), "secret");
+        assert_eq!(client.submit(1, Part::One, "a b"), Ok(Verdict::Incorrect));
+        assert!(matches!(
+            client.submit(1, Part::One, "142"),
+            Ok(Verdict::Wait(left)) if left.as_secs() <= 60
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use common::DynSolution;
+
+/// Solution of the given day, with the puzzle parameters.
+pub fn solution(day: u8) -> Option<Box<dyn DynSolution>> {
+    let solution: Box<dyn DynSolution> = match day {
+        1 => Box::new(day_01::Day01::default()),
+        2 => Box::new(day_02::Day02::default()),
+        3 => Box::new(day_03::Day03),
+        4 => Box::new(day_04::Day04),
+        5 => Box::new(day_05::Day05),
+        6 => Box::new(day_06::Day06),
+        7 => Box::new(day_07::Day07),
+        8 => Box::new(day_08::Day08),
+        9 => Box::new(day_09::Day09),
+        10 => Box::new(day_10::Day10),
+        11 => Box::new(day_11::Day11::default()),
+        12 => Box::new(day_12::Day12),
+        13 => Box::new(day_13::Day13),
+        14 => Box::new(day_14::Day14::default()),
+        15 => Box::new(day_15::Day15),
+        16 => Box::new(day_16::Day16),
+        17 => Box::new(day_17::Day17),
+        18 => Box::new(day_18::Day18),
+        19 => Box::new(day_19::Day19),
+        20 => Box::new(day_20::Day20),
+        21 => Box::new(day_21::Day21::default()),
+        22 => Box::new(day_22::Day22),
+        23 => Box::new(day_23::Day23),
+        24 => Box::new(day_24::Day24::default()),
+        25 => Box::new(day_25::Day25),
+        _ => return None,
+    };
+    Some(solution)
+}
+
+/// Solution of the given day with some of its parameters changed, given as the name of the field
+/// of the day and its value.
+pub fn solution_with(
+    day: u8,
+    parameters: &[(String, String)],
+) -> Result<Box<dyn DynSolution>, String> {
+    let solution: Box<dyn DynSolution> = match day {
+        1 => {
+            let mut solution = day_01::Day01::default();
+            for (name, value) in parameters {
+                match name.as_str() {
+                    "vocabulary" => solution.vocabulary = day_01::Vocabulary::load(value)?,
+                    _ => return Err(unknown_parameter(day, name)),
+                }
+            }
+            Box::new(solution)
+        }
+        11 => {
+            let mut solution = day_11::Day11::default();
+            for (name, value) in parameters {
+                match name.as_str() {
+                    "expansion" => set(&mut solution.expansion, name, value)?,
+                    _ => return Err(unknown_parameter(day, name)),
+                }
+            }
+            Box::new(solution)
+        }
+        14 => {
+            let mut solution = day_14::Day14::default();
+            for (name, value) in parameters {
+                match name.as_str() {
+                    "cycles" => set(&mut solution.cycles, name, value)?,
+                    _ => return Err(unknown_parameter(day, name)),
+                }
+            }
+            Box::new(solution)
+        }
+        21 => {
+            let mut solution = day_21::Day21::default();
+            for (name, value) in parameters {
+                match name.as_str() {
+                    "part_1_steps" => set(&mut solution.part_1_steps, name, value)?,
+                    "part_2_steps" => set(&mut solution.part_2_steps, name, value)?,
+                    "offset" => set(&mut solution.offset, name, value)?,
+                    _ => return Err(unknown_parameter(day, name)),
+                }
+            }
+            Box::new(solution)
+        }
+        24 => {
+            let mut solution = day_24::Day24::default();
+            for (name, value) in parameters {
+                match name.as_str() {
+                    "min_box" => set(&mut solution.min_box, name, value)?,
+                    "max_box" => set(&mut solution.max_box, name, value)?,
+                    _ => return Err(unknown_parameter(day, name)),
+                }
+            }
+            Box::new(solution)
+        }
+        _ => match parameters.first() {
+            Some((name, _)) => return Err(unknown_parameter(day, name)),
+            None => solution(day).ok_or(format!("day {day} is not solved yet"))?,
+        },
+    };
+    Ok(solution)
+}
+
+fn set<T: FromStr>(field: &mut T, name: &str, value: &str) -> Result<(), String> {
+    *field = value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for `{name}`"))?;
+    Ok(())
+}
+
+fn unknown_parameter(day: u8, name: &str) -> String {
+    format!("day {day} has no parameter `{name}`")
+}
@@ -0,0 +1,307 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use common::Part;
+
+use crate::{inputs_dir, parse_day};
+
+/// Example input of a part and the answer the puzzle text gives for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+/// `aoc examples <day> <page.html>`
+pub fn examples(args: &[String]) -> Result<(), String> {
+    let (day, page_path) = match args {
+        [day, page] => (parse_day(day)?, PathBuf::from(page)),
+        [] | [_] => return Err("expected a day and a saved puzzle page".to_string()),
+        [_, _, arg, ..] => return Err(format!("unexpected argument `{arg}`")),
+    };
+    let page = std::fs::read_to_string(&page_path)
+        .map_err(|err| format!("could not read `{}`: {err}", page_path.display()))?;
+    let examples = extract(&page);
+    if examples.is_empty() {
+        return Err(format!(
+            "no example with an answer found in `{}`",
+            page_path.display()
+        ));
+    }
+
+    let lib = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+        .join(format!("day-{day:02}"))
+        .join("src")
+        .join("lib.rs");
+    let mut source = std::fs::read_to_string(&lib)
+        .map_err(|err| format!("could not read `{}`: {err}", lib.display()))?;
+    if source.contains("mod tests_examples {") {
+        return Err(format!(
+            "`{}` already has a `tests_examples` module, remove it to generate it again",
+            lib.display()
+        ));
+    }
+
+    let input_dir = inputs_dir().join(format!("day-{day:02}"));
+    let files = write_inputs(&input_dir, &examples)?;
+    for (example, file) in examples.iter().zip(&files) {
+        println!(
+            "Part {}: `{file}` gives {}",
+            example.part.number(),
+            example.answer
+        );
+    }
+
+    // Days with settings are built from their default ones
+    let solution = if source.contains(&format!("pub struct Day{day:02} {{")) {
+        format!("Day{day:02}::default()")
+    } else {
+        format!("Day{day:02}")
+    };
+    source += &test_module(day, &solution, &examples, &files);
+    std::fs::write(&lib, source)
+        .map_err(|err| format!("could not write `{}`: {err}", lib.display()))?;
+    println!(
+        "Tests added to `{}`, check them against the puzzle text.",
+        lib.display()
+    );
+    Ok(())
+}
+
+/// Examples of a saved puzzle page: each `<pre><code>` block of a part with the last
+/// `<code><em>` answer before the next block. Part 2 answers given before its first block are
+/// for the first example of part 1.
+pub fn extract(page: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    for (index, article) in articles(page).into_iter().take(2).enumerate() {
+        let part = if index == 0 { Part::One } else { Part::Two };
+        let blocks = find_all(article, "<pre><code>", "</code></pre>");
+        let answers = find_all(article, "<code><em>", "</em></code>");
+        let answer_in = |from: usize, to: usize| {
+            answers
+                .iter()
+                .rfind(|(start, _, _)| (from..to).contains(start))
+                .map(|(_, _, answer)| decode(answer))
+        };
+
+        let first_block = blocks.first().map_or(article.len(), |(start, _, _)| *start);
+        let first_input = examples.first().map(|example| example.input.clone());
+        if let (Part::Two, Some(input), Some(answer)) =
+            (part, first_input, answer_in(0, first_block))
+        {
+            examples.push(Example {
+                part,
+                input,
+                answer,
+            });
+        }
+        for (i, (_, end, block)) in blocks.iter().enumerate() {
+            let next = blocks
+                .get(i + 1)
+                .map_or(article.len(), |(start, _, _)| *start);
+            if let Some(answer) = answer_in(*end, next) {
+                let example = Example {
+                    part,
+                    input: decode(block),
+                    answer,
+                };
+                if !examples.contains(&example) {
+                    examples.push(example);
+                }
+            }
+        }
+    }
+    examples
+}
+
+/// Puzzle descriptions of the page, one `<article>` per part.
+fn articles(page: &str) -> Vec<&str> {
+    find_all(page, "<article", "</article>")
+        .into_iter()
+        .map(|(_, _, article)| article)
+        .collect()
+}
+
+/// Text between every `open` and the next `close`, with its start and end in `text`.
+fn find_all<'a>(text: &'a str, open: &str, close: &str) -> Vec<(usize, usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = text[offset..].find(open).map(|start| offset + start) {
+        let content = start + open.len();
+        let Some(length) = text[content..].find(close) else {
+            break;
+        };
+        offset = content + length + close.len();
+        found.push((start, offset, &text[content..content + length]));
+    }
+    found
+}
+
+/// Text of an HTML fragment, without its tags.
+fn decode(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Saves the example inputs in `dir` as `test.txt`, `test2.txt`, ... and returns the file of
+/// each example. Inputs already saved are reused and only empty files are overwritten.
+pub fn write_inputs(dir: &Path, examples: &[Example]) -> Result<Vec<String>, String> {
+    std::fs::create_dir_all(dir)
+        .map_err(|err| format!("could not create `{}`: {err}", dir.display()))?;
+    let mut files = Vec::new();
+    for example in examples {
+        let mut index = 1;
+        let file = loop {
+            let file = match index {
+                1 => "test.txt".to_string(),
+                index => format!("test{index}.txt"),
+            };
+            let existing = std::fs::read_to_string(dir.join(&file)).unwrap_or_default();
+            if existing.is_empty() {
+                let path = dir.join(&file);
+                std::fs::write(&path, &example.input)
+                    .map_err(|err| format!("could not write `{}`: {err}", path.display()))?;
+                break file;
+            }
+            if existing.trim_end() == example.input.trim_end() {
+                break file;
+            }
+            index += 1;
+        };
+        files.push(file);
+    }
+    Ok(files)
+}
+
+/// Test module checking the answers of the examples, to append to the day crate.
+pub fn test_module(day: u8, solution: &str, examples: &[Example], files: &[String]) -> String {
+    let mut module = String::from(
+        "\n#[cfg(test)]\nmod tests_examples {\n    use super::*;\n    use common::{Answer, Part};\n",
+    );
+    for (index, (example, file)) in examples.iter().zip(files).enumerate() {
+        let part = match example.part {
+            Part::One => "Part::One",
+            Part::Two => "Part::Two",
+        };
+        // Spelled out variants, an unsuffixed literal would be typed `i32` by `.into()`.
+        let answer = match example.answer.parse::<i128>() {
+            Ok(number) => format!("Answer::Number({number})"),
+            Err(_) => format!("Answer::Text({:?}.into())", example.answer),
+        };
+        write!(
+            module,
+            "\n    #[test]\n    fn test_example_{}() {{\n        \
+             let input = include_str!(\"../../aoc-2023-inputs/day-{day:02}/{file}\");\n        \
+             assert_eq!({solution}.solve(input, {part}), Ok({answer}));\n    }}\n",
+            index + 1
+        )
+        .unwrap();
+    }
+    module + "}\n"
+}
+
+#[cfg(test)]
+mod tests_examples {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+<p>Another example, with no answer:</p>
+<pre><code>a &lt;b&gt; &amp; <em>c</em>
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the same lines, the sum is now <code><em>142</em></code>.</p>
+<p>With spelled out digits:</p>
+<pre><code>two1nine
+eightwothree
+</code></pre>
+<p>Adding these together produces <code><em>29</em></code> then <code><em>112</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        let first = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        let example = |part, input: &str, answer: &str| Example {
+            part,
+            input: input.to_string(),
+            answer: answer.to_string(),
+        };
+        assert_eq!(
+            extract(PAGE),
+            vec![
+                example(Part::One, first, "142"),
+                example(Part::Two, first, "142"),
+                example(Part::Two, "two1nine\neightwothree\n", "112"),
+            ]
+        );
+        assert_eq!(decode("a &lt;b&gt; &amp;lt; <em>c</em>"), "a <b> &lt; c");
+        assert_eq!(extract("<html></html>"), vec![]);
+    }
+
+    #[test]
+    fn test_write_inputs_and_module() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("test.txt"), "").unwrap();
+        std::fs::write(dir.join("test2.txt"), "kept\n").unwrap();
+
+        let examples = extract(PAGE);
+        let files = write_inputs(&dir, &examples).unwrap();
+        assert_eq!(files, ["test.txt", "test.txt", "test3.txt"]);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("test2.txt")).unwrap(),
+            "kept\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join("test3.txt")).unwrap(),
+            "two1nine\neightwothree\n"
+        );
+        assert_eq!(write_inputs(&dir, &examples).unwrap(), files);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let module = test_module(1, "Day01", &examples[2..], &files[2..]);
+        assert_eq!(
+            module,
+            "\n#[cfg(test)]\nmod tests_examples {\n    use super::*;\n    use common::{Answer, Part};\n\n    \
+             #[test]\n    fn test_example_1() {\n        \
+             let input = include_str!(\"../../aoc-2023-inputs/day-01/test3.txt\");\n        \
+             assert_eq!(Day01.solve(input, Part::Two), Ok(Answer::Number(112)));\n    }\n}\n"
+        );
+        let example = |answer: &str| Example {
+            part: Part::One,
+            input: String::new(),
+            answer: answer.to_string(),
+        };
+        let module =
+            |answer| test_module(5, "Day05", &[example(answer)], &["test.txt".to_string()]);
+        assert!(module("abc").contains("Ok(Answer::Text(\"abc\".into()))"));
+        assert!(module("952408144115").contains("Ok(Answer::Number(952408144115))"));
+        assert!(
+            module("167409079868000000000").contains("Ok(Answer::Number(167409079868000000000))")
+        );
+    }
+}
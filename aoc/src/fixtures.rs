@@ -0,0 +1,251 @@
+use std::path::{Path, PathBuf};
+
+use common::{parse_answer, Answer, Part};
+
+use crate::{days, inputs_dir, parse_day, read_input};
+
+/// Path of the fixtures manifest, at the root of the workspace.
+pub fn manifest_path() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../fixtures.txt"))
+}
+
+/// Example of the manifest, a line such as `11 2 test.txt 1030 expansion=10`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Case {
+    /// Line of the case in the manifest.
+    pub line: usize,
+    pub day: u8,
+    pub part: Part,
+    /// Input file, in the inputs directory of the day.
+    pub file: String,
+    pub answer: Answer,
+    /// Fields of the day changed from their default value.
+    pub parameters: Vec<(String, String)>,
+}
+
+/// `aoc test [<day>...]`, runs the cases of the manifest for the given days (default: all).
+pub fn test(args: &[String]) -> Result<(), String> {
+    let selected_days = args
+        .iter()
+        .map(|arg| parse_day(arg))
+        .collect::<Result<Vec<u8>, String>>()?;
+    let path = manifest_path();
+    let text = std::fs::read_to_string(&path)
+        .map_err(|err| format!("could not read `{}`: {err}", path.display()))?;
+    let cases = parse(&text).map_err(|err| format!("{}:{err}", path.display()))?;
+
+    let mut failed = 0;
+    for case in cases
+        .iter()
+        .filter(|case| selected_days.is_empty() || selected_days.contains(&case.day))
+    {
+        let status = match run_case(case, &inputs_dir()) {
+            Ok(()) => "ok".to_string(),
+            Err(err) => {
+                failed += 1;
+                format!("FAILED, {err}")
+            }
+        };
+        let parameters: String = case
+            .parameters
+            .iter()
+            .map(|(name, value)| format!(" {name}={value}"))
+            .collect();
+        println!(
+            "Day {:02} part {} {}{parameters}: {status}",
+            case.day,
+            case.part.number(),
+            case.file
+        );
+    }
+    match failed {
+        0 => Ok(()),
+        1 => Err("1 case failed".to_string()),
+        failed => Err(format!("{failed} cases failed")),
+    }
+}
+
+/// Cases of the manifest: a case per line, `#` starts a comment.
+pub fn parse(text: &str) -> Result<Vec<Case>, String> {
+    let mut cases = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let error = |message: &str| format!("{}: {message}", index + 1);
+        let tokens = tokenize(line).ok_or_else(|| error("unterminated string"))?;
+        let [day, part, file, answer, parameters @ ..] = tokens.as_slice() else {
+            if tokens.is_empty() {
+                continue;
+            }
+            return Err(error(
+                "expected `<day> <part> <file> <answer> [<name>=<value>...]`",
+            ));
+        };
+        let day = day
+            .parse::<u8>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| error(&format!("invalid day `{day}`")))?;
+        let part = match part.as_str() {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return Err(error(&format!("invalid part `{part}`"))),
+        };
+        let answer =
+            parse_answer(answer).ok_or_else(|| error(&format!("invalid answer `{answer}`")))?;
+        let parameters = parameters
+            .iter()
+            .map(|parameter| {
+                parameter
+                    .split_once('=')
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .ok_or_else(|| error(&format!("invalid parameter `{parameter}`")))
+            })
+            .collect::<Result<_, _>>()?;
+        cases.push(Case {
+            line: index + 1,
+            day,
+            part,
+            file: file.clone(),
+            answer,
+            parameters,
+        });
+    }
+    Ok(cases)
+}
+
+/// Words of a line up to its comment, quoted strings are kept whole with their quotes.
+fn tokenize(line: &str) -> Option<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => break,
+            c if c.is_whitespace() => continue,
+            _ => {
+                let mut token = String::from(c);
+                let mut quoted = c == '"';
+                while let Some(&c) = chars.peek() {
+                    if !quoted && (c.is_whitespace() || c == '#') {
+                        break;
+                    }
+                    chars.next();
+                    token.push(c);
+                    match c {
+                        '\\' if quoted => token.push(chars.next()?),
+                        '"' => quoted = !quoted,
+                        _ => {}
+                    }
+                }
+                if quoted {
+                    return None;
+                }
+                tokens.push(token);
+            }
+        }
+    }
+    Some(tokens)
+}
+
+/// Solves `case` with the input in `inputs`, an error describes what went wrong.
+pub fn run_case(case: &Case, inputs: &Path) -> Result<(), String> {
+    let path = inputs.join(format!("day-{:02}", case.day)).join(&case.file);
+    let input = read_input(path)?;
+    let solution = days::solution_with(case.day, &case.parameters)?;
+    let parsed = solution
+        .parse_dyn(&input)
+        .map_err(|err| format!("invalid input: {}", err.diagnostic(&input)))?;
+    let answer = solution.part_dyn(parsed.as_ref(), case.part);
+    if answer != case.answer {
+        return Err(format!("expected {}, got {answer}", case.answer));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests_fixtures {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = "# day part file answer\n\n\
+                    11 2 test.txt 1030 expansion=10  # comment\n\
+                    03 1 test2.txt \"a \\\"b\\\" # c\"\n";
+        assert_eq!(
+            parse(text),
+            Ok(vec![
+                Case {
+                    line: 3,
+                    day: 11,
+                    part: Part::Two,
+                    file: "test.txt".to_string(),
+                    answer: 1030.into(),
+                    parameters: vec![("expansion".to_string(), "10".to_string())],
+                },
+                Case {
+                    line: 4,
+                    day: 3,
+                    part: Part::One,
+                    file: "test2.txt".to_string(),
+                    answer: "a \"b\" # c".into(),
+                    parameters: vec![],
+                }
+            ])
+        );
+        assert_eq!(
+            parse("1 1 test.txt"),
+            Err("1: expected `<day> <part> <file> <answer> [<name>=<value>...]`".to_string())
+        );
+        assert_eq!(
+            parse("26 1 test.txt 3"),
+            Err("1: invalid day `26`".to_string())
+        );
+        assert_eq!(
+            parse("2 1 test.txt 3 steps"),
+            Err("1: invalid parameter `steps`".to_string())
+        );
+        assert_eq!(
+            parse("2 1 test.txt \"3"),
+            Err("1: unterminated string".to_string())
+        );
+    }
+
+    #[test]
+    fn test_solution_with() {
+        assert!(days::solution_with(11, &[("expansion".into(), "10".into())]).is_ok());
+        assert!(days::solution_with(7, &[]).is_ok());
+        assert_eq!(
+            days::solution_with(7, &[("cycles".into(), "3".into())]).err(),
+            Some("day 7 has no parameter `cycles`".to_string())
+        );
+        assert_eq!(
+            days::solution_with(14, &[("cycles".into(), "-3".into())]).err(),
+            Some("invalid value `-3` for `cycles`".to_string())
+        );
+        assert!(
+            days::solution_with(1, &[("vocabulary".into(), "missing.txt".into())])
+                .err()
+                .is_some_and(|err| err.starts_with("could not read `missing.txt`"))
+        );
+    }
+
+    /// Every case of the manifest.
+    #[test]
+    fn test_fixtures() {
+        let text = std::fs::read_to_string(manifest_path()).unwrap();
+        let cases = parse(&text).unwrap();
+        let failures: Vec<String> = cases
+            .iter()
+            .filter_map(|case| {
+                run_case(case, &inputs_dir()).err().map(|err| {
+                    format!(
+                        "fixtures.txt:{}: day {} part {} `{}`: {err}",
+                        case.line,
+                        case.day,
+                        case.part.number(),
+                        case.file
+                    )
+                })
+            })
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
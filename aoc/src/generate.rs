@@ -0,0 +1,1098 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fmt::Write as _;
+
+use common::{Direction, Grid, Point2, Point3, Rng};
+
+use crate::parse_day;
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// `aoc gen <day> [--seed N] [--size N]`
+pub fn gen(args: &[String]) -> Result<(), String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("missing value for `--seed`")?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid seed `{value}`, expected a number"))?;
+            }
+            "--size" => {
+                let value = args.next().ok_or("missing value for `--size`")?;
+                size = match value.parse() {
+                    Ok(size @ 1..) => Some(size),
+                    _ => {
+                        return Err(format!(
+                            "invalid size `{value}`, expected a positive number"
+                        ))
+                    }
+                };
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    print!("{}", generate(day, seed, size));
+    Ok(())
+}
+
+/// Random valid input of `day`, the same seed and size always give the same input.
+///
+/// The meaning of `size` depends on the day (lines, side of the map, ...), `None` gives about
+/// the size of a real puzzle input.
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> String {
+    type Generator = fn(&mut Rng, usize) -> String;
+    let (generator, default_size): (Generator, usize) = match day {
+        1 => (calibration_document, 1000),
+        2 => (cube_games, 100),
+        3 => (engine_schematic, 140),
+        4 => (scratchcards, 200),
+        5 => (almanac, 30),
+        6 => (race_sheet, 4),
+        7 => (camel_hands, 1000),
+        8 => (|rng, size| ghost_network(rng, size).0, 6),
+        9 => (oasis_report, 200),
+        10 => (pipe_maze, 140),
+        11 => (galaxy_image, 140),
+        12 => (spring_records, 1000),
+        13 => (mirror_patterns, 100),
+        14 => (rock_platform, 100),
+        15 => (init_sequence, 4000),
+        16 => (mirror_contraption, 110),
+        17 => (heat_map, 141),
+        18 => (dig_plan, 700),
+        19 => (workflows, 550),
+        20 => (|rng, size| counter_network(rng, size).0, 12),
+        21 => (garden_map, 131),
+        22 => (brick_snapshot, 1200),
+        23 => (hiking_trails, 6),
+        24 => (|rng, size| hailstorm(rng, size).0, 300),
+        25 => (wiring_diagram, 1500),
+        _ => panic!("there is no puzzle on day {day}"),
+    };
+    generator(&mut Rng::new(seed), size.unwrap_or(default_size))
+}
+
+fn lowercase(rng: &mut Rng) -> char {
+    char::from(*rng.choose(LOWERCASE))
+}
+
+/// Lowercase name of `length` letters that is not in `used` yet.
+fn unique_name(rng: &mut Rng, used: &mut HashSet<String>, length: usize) -> String {
+    loop {
+        let name: String = (0..length).map(|_| lowercase(rng)).collect();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// `size` lines of letters, digits and spelled digits, with at least one digit each.
+fn calibration_document(rng: &mut Rng, size: usize) -> String {
+    const SPELLED: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let mut input = String::new();
+    for _ in 0..size {
+        let tokens = rng.below(6) + 1;
+        let digit = rng.below(tokens);
+        for token in 0..tokens {
+            if token == digit || rng.chance(0.2) {
+                input.push(char::from(b'1' + rng.below(9) as u8));
+            } else if rng.chance(0.5) {
+                let spelled = rng.below(SPELLED.len());
+                input.push_str(SPELLED[spelled]);
+            } else {
+                for _ in 0..rng.range(1..=4) {
+                    input.push(lowercase(rng));
+                }
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// `size` games of up to 6 rounds.
+fn cube_games(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let rounds = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let count = rng.range(1..=3) as usize;
+                colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        writeln!(input, "Game {id}: {rounds}").unwrap();
+    }
+    input
+}
+
+/// Square schematic of side `size`.
+fn engine_schematic(rng: &mut Rng, size: usize) -> String {
+    const SYMBOLS: &[u8] = b"#+$/@=%-&";
+    let mut schematic = Grid::new(size, size, '.');
+    for y in 0..size {
+        let mut x = rng.below(4);
+        while x < size {
+            let length = (rng.below(3) + 1).min(size - x) as u32;
+            let number = rng.range(10i64.pow(length - 1)..=10i64.pow(length) - 1);
+            for (i, digit) in number.to_string().chars().enumerate() {
+                schematic[(x + i, y)] = digit;
+            }
+            x += length as usize + 1 + rng.below(6);
+        }
+    }
+    schematic
+        .map(|cell| match cell {
+            '.' if rng.chance(0.03) => '*',
+            '.' if rng.chance(0.05) => char::from(*rng.choose(SYMBOLS)),
+            cell => *cell,
+        })
+        .to_string()
+}
+
+/// `size` cards, the number of copies stays within a `u32`.
+fn scratchcards(rng: &mut Rng, size: usize) -> String {
+    // A card with that many copies gets no matches, so that each card has at most 11 times as
+    // many copies and the total fits
+    let max_copies = (u32::MAX as u64 / (11 * size as u64)).max(1);
+    let mut copies = vec![1; size];
+    let mut input = String::new();
+    for card in 0..size {
+        let mut numbers: Vec<i64> = (1..=99).collect();
+        rng.shuffle(&mut numbers);
+        let mut matches = if rng.chance(0.1) {
+            rng.below(11)
+        } else {
+            rng.below(3)
+        };
+        matches = matches.min(size - card - 1);
+        if copies[card] > max_copies {
+            matches = 0;
+        }
+        for next in card + 1..=card + matches {
+            copies[next] += copies[card];
+        }
+
+        let (winning, others) = numbers.split_at(10);
+        let mut hand = [&winning[..matches], &others[..25 - matches]].concat();
+        rng.shuffle(&mut hand);
+        let join = |numbers: &[i64]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        writeln!(
+            input,
+            "Card {:>3}: {} | {}",
+            card + 1,
+            join(winning),
+            join(&hand)
+        )
+        .unwrap();
+    }
+    input
+}
+
+/// Maps made of `size` ranges, each map shuffles the numbers below 4 billions.
+fn almanac(rng: &mut Rng, size: usize) -> String {
+    almanac_below(rng, size, 4_000_000_000)
+}
+
+/// Same as [`almanac`], shuffling the numbers below `max` instead.
+fn almanac_below(rng: &mut Rng, size: usize, max: i64) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    let mut input = String::from("seeds:");
+    for _ in 0..10 {
+        let length = rng.range(1..=max / 20);
+        write!(input, " {} {length}", rng.range(0..=max - length)).unwrap();
+    }
+    input.push('\n');
+    for name in MAPS {
+        write!(input, "\n{name} map:\n").unwrap();
+        let mut cuts: Vec<i64> = (1..size).map(|_| rng.range(1..=max - 1)).collect();
+        cuts.extend([0, max]);
+        cuts.sort();
+        cuts.dedup();
+        let mut segments: Vec<(i64, i64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        rng.shuffle(&mut segments);
+        let mut destination = 0;
+        for (source, length) in segments {
+            writeln!(input, "{destination} {source} {length}").unwrap();
+            destination += length;
+        }
+    }
+    input
+}
+
+/// `size` races, at most 4 so that the single race of part 2 fits in a `u64`.
+fn race_sheet(rng: &mut Rng, size: usize) -> String {
+    let mut times = String::from("Time:    ");
+    let mut distances = String::from("Distance:");
+    for _ in 0..size.min(4) {
+        let time = rng.range(7..=99);
+        let distance = rng.range(0..=time / 2 * (time - time / 2) - 1);
+        write!(times, " {time:>6}").unwrap();
+        write!(distances, " {distance:>6}").unwrap();
+    }
+    format!("{times}\n{distances}\n")
+}
+
+/// `size` distinct hands of every type.
+fn camel_hands(rng: &mut Rng, size: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut hands = HashSet::new();
+    let mut input = String::new();
+    while hands.len() < size.min(CARDS.len().pow(5)) {
+        let mut labels = CARDS.to_vec();
+        rng.shuffle(&mut labels);
+        labels.truncate(rng.below(5) + 1);
+        let hand: String = (0..5).map(|_| char::from(*rng.choose(&labels))).collect();
+        if hands.insert(hand.clone()) {
+            writeln!(input, "{hand} {}", rng.range(1..=1000)).unwrap();
+        }
+    }
+    input
+}
+
+/// `size` ghosts (at most 12), the first one going from `AAA` to `ZZZ`. Each ghost walks a cycle of
+/// nodes that only follows the instructions, returns the steps of each ghost to its end node.
+fn ghost_network(rng: &mut Rng, size: usize) -> (String, Vec<u64>) {
+    const PRIMES: [usize; 12] = [11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+    const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    const LAST: &[u8] = b"0123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+
+    let path_length = *rng.choose(&[5, 7, 11, 13, 17, 19, 23, 29, 31]);
+    let path: String = (0..path_length)
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect();
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+
+    let mut used = HashSet::new();
+    let mut fresh = |rng: &mut Rng, last: &[u8]| loop {
+        let name: String = [ALPHANUMERIC, ALPHANUMERIC, last]
+            .iter()
+            .map(|letters| char::from(*rng.choose(letters)))
+            .collect();
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+    let trap = fresh(rng, LAST);
+    let mut lines = vec![format!("{trap} = ({trap}, {trap})")];
+    let mut steps = Vec::new();
+    for (ghost, prime) in primes.iter().take(size.clamp(1, PRIMES.len())).enumerate() {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            // Ghosts are told apart by the prefix of their start and end nodes
+            loop {
+                let name = fresh(rng, b"A");
+                if !matches!(&name[..2], "AA" | "ZZ") {
+                    break (name.clone(), format!("{}Z", &name[..2]));
+                }
+            }
+        };
+        let length = path_length * prime;
+        let mut nodes = vec![start];
+        nodes.extend((1..length).map(|_| fresh(rng, LAST)));
+        nodes.push(end);
+        for (step, node) in nodes.iter().enumerate() {
+            let next = &nodes[step % length + 1];
+            let line = match path.as_bytes()[step % path_length] {
+                b'L' => format!("{node} = ({next}, {trap})"),
+                _ => format!("{node} = ({trap}, {next})"),
+            };
+            lines.push(line);
+        }
+        steps.push(length as u64);
+    }
+    rng.shuffle(&mut lines);
+    (format!("{path}\n\n{}\n", lines.join("\n")), steps)
+}
+
+/// `size` histories of 21 values of polynomials of degree up to 6.
+fn oasis_report(rng: &mut Rng, size: usize) -> String {
+    let binomial = |n: i64, k: i64| (0..k).fold(1, |c, i| c * (n - i) / (i + 1));
+    let mut input = String::new();
+    for _ in 0..size {
+        let coefficients: Vec<i64> = (0..=rng.range(0..=6)).map(|_| rng.range(-9..=9)).collect();
+        let values = (0..21)
+            .map(|x| {
+                let value: i64 = (0..)
+                    .zip(&coefficients)
+                    .map(|(k, c)| c * binomial(x, k))
+                    .sum();
+                value.to_string()
+            })
+            .collect::<Vec<_>>();
+        writeln!(input, "{}", values.join(" ")).unwrap();
+    }
+    input
+}
+
+/// Square map of side `size` (at least 4). The loop goes around a random tree of corridors, the
+/// other tiles are junk pipes.
+fn pipe_maze(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(4);
+    let nodes = (side - 4) / 4 + 1;
+
+    // Random spanning tree of nodes placed every 4 tiles, explored depth first
+    let mut corridors = Grid::new(side, side, false);
+    let mut visited = Grid::new(nodes, nodes, false);
+    let start = (rng.below(nodes), rng.below(nodes));
+    visited[start] = true;
+    corridors[(4 * start.0 + 2, 4 * start.1 + 2)] = true;
+    let mut stack = vec![start];
+    while let Some(&(x, y)) = stack.last() {
+        let next: Vec<_> = visited
+            .neighbours4(x, y)
+            .filter(|position| !visited[*position])
+            .collect();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (next_x, next_y) = *rng.choose(&next);
+        visited[(next_x, next_y)] = true;
+        for i in 0..=4 {
+            let tile_x = 4 * x.min(next_x) + 2 + i * x.abs_diff(next_x);
+            let tile_y = 4 * y.min(next_y) + 2 + i * y.abs_diff(next_y);
+            corridors[(tile_x, tile_y)] = true;
+        }
+        stack.push((next_x, next_y));
+    }
+
+    // The loop is made of the tiles touching the corridors
+    let is_loop = Grid::from_fn(side, side, |x, y| {
+        !corridors[(x, y)] && corridors.neighbours8(x, y).any(|p| corridors[p])
+    });
+    let mut loop_tiles = Vec::new();
+    let mut map = Grid::from_fn(side, side, |x, y| {
+        if !is_loop[(x, y)] {
+            return *rng.choose(b"|-LJ7F...") as char;
+        }
+        loop_tiles.push(Point2::new(x, y));
+        let open = Direction::ALL.map(|direction| {
+            Point2::new(x, y)
+                .step(direction)
+                .is_some_and(|p| is_loop.get(p.x, p.y) == Some(&true))
+        });
+        match open {
+            [true, false, true, false] => '|',
+            [false, true, false, true] => '-',
+            [true, true, false, false] => 'L',
+            [true, false, false, true] => 'J',
+            [false, true, true, false] => 'F',
+            [false, false, true, true] => '7',
+            _ => unreachable!("loop tile connected to {open:?}"),
+        }
+    });
+    let start = *rng.choose(&loop_tiles);
+    map[start] = 'S';
+    for (x, y) in map.neighbours4(start.x, start.y).collect::<Vec<_>>() {
+        if !is_loop[(x, y)] {
+            map[(x, y)] = '.';
+        }
+    }
+    map.to_string()
+}
+
+/// Square image of side `size`, with about a tenth of the rows and columns empty.
+fn galaxy_image(rng: &mut Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+    Grid::from_fn(size, size, |x, y| {
+        if !empty_rows[y] && !empty_columns[x] && rng.chance(0.03) {
+            '#'
+        } else {
+            '.'
+        }
+    })
+    .to_string()
+}
+
+/// `size` records of up to 20 springs, each with at least one arrangement.
+fn spring_records(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let length = rng.range(5..=20) as usize;
+        let mut springs: Vec<char> = (0..length)
+            .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+            .collect();
+        springs[rng.below(length)] = '#';
+        let groups = springs
+            .split(|spring| *spring == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect::<Vec<_>>();
+        let record: String = springs
+            .iter()
+            .map(|spring| if rng.chance(0.5) { '?' } else { *spring })
+            .collect();
+        writeln!(input, "{record} {}", groups.join(",")).unwrap();
+    }
+    input
+}
+
+/// `size` patterns with a line of reflection, and another one once a smudge is fixed.
+fn mirror_patterns(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| mirror_pattern(rng).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Pattern reflected on a row and, but for one smudge, on a column, randomly transposed.
+fn mirror_pattern(rng: &mut Rng) -> Grid<char> {
+    let (width, height) = (rng.range(5..=17) as usize, rng.range(5..=17) as usize);
+    // Some rows must be out of the reflection to put the smudge there
+    let row_mirror = loop {
+        let mirror = rng.range(1..=height as i64 - 1) as usize;
+        if 2 * mirror != height {
+            break mirror;
+        }
+    };
+    let column_mirror = rng.range(1..=width as i64 - 1) as usize;
+    let reach = |mirror: usize, length: usize| mirror.min(length - mirror);
+    let fold = |i: usize, mirror: usize, length: usize| {
+        if (mirror..mirror + reach(mirror, length)).contains(&i) {
+            2 * mirror - 1 - i
+        } else {
+            i
+        }
+    };
+
+    let cells = Grid::from_fn(
+        width,
+        height,
+        |_, _| {
+            if rng.chance(0.5) {
+                '#'
+            } else {
+                '.'
+            }
+        },
+    );
+    let mut pattern = Grid::from_fn(width, height, |x, y| {
+        cells[(fold(x, column_mirror, width), fold(y, row_mirror, height))]
+    });
+    let row_reach = reach(row_mirror, height);
+    let rows: Vec<usize> = (0..height)
+        .filter(|y| y + row_reach < row_mirror || *y >= row_mirror + row_reach)
+        .collect();
+    let column_reach = reach(column_mirror, width);
+    let smudge = (
+        rng.range((column_mirror - column_reach) as i64..=(column_mirror + column_reach - 1) as i64)
+            as usize,
+        *rng.choose(&rows),
+    );
+    pattern[smudge] = if pattern[smudge] == '#' { '.' } else { '#' };
+
+    if rng.chance(0.5) {
+        pattern.transpose()
+    } else {
+        pattern
+    }
+}
+
+/// Square platform of side `size`.
+fn rock_platform(rng: &mut Rng, size: usize) -> String {
+    Grid::from_fn(size, size, |_, _| match rng.below(20) {
+        0..=3 => 'O',
+        4..=6 => '#',
+        _ => '.',
+    })
+    .to_string()
+}
+
+/// `size` steps on about `size / 8` labels.
+fn init_sequence(rng: &mut Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..(size / 8).max(1))
+        .map(|_| (0..rng.range(2..=6)).map(|_| lowercase(rng)).collect())
+        .collect();
+    let steps = (0..size)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.chance(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.range(1..=9))
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("{}\n", steps.join(","))
+}
+
+/// Square contraption of side `size`.
+fn mirror_contraption(rng: &mut Rng, size: usize) -> String {
+    Grid::from_fn(size, size, |_, _| {
+        if rng.chance(0.1) {
+            *rng.choose(b"/\\|-") as char
+        } else {
+            '.'
+        }
+    })
+    .to_string()
+}
+
+/// Square map of side `size`.
+fn heat_map(rng: &mut Rng, size: usize) -> String {
+    Grid::from_fn(size, size, |_, _| char::from(b'1' + rng.below(9) as u8)).to_string()
+}
+
+/// About `size` instructions. Both the plan and the colors dig a simple polygon.
+fn dig_plan(rng: &mut Rng, size: usize) -> String {
+    let columns = (size / 4).max(1);
+    let plan = trench_outline(rng, columns, 6);
+    let colors = trench_outline(rng, columns, 0xfffff / (2 * columns as i64 + 2));
+    let mut input = String::new();
+    for ((direction, length), (color_direction, color_length)) in plan.into_iter().zip(colors) {
+        let letter = match direction {
+            Direction::North => 'U',
+            Direction::East => 'R',
+            Direction::South => 'D',
+            Direction::West => 'L',
+        };
+        let digit = match color_direction {
+            Direction::East => 0,
+            Direction::South => 1,
+            Direction::West => 2,
+            Direction::North => 3,
+        };
+        writeln!(input, "{letter} {length} (#{color_length:05x}{digit})").unwrap();
+    }
+    input
+}
+
+/// Clockwise outline of `columns` side by side vertical spans, each overlapping the next one so
+/// the outline is a simple polygon. Gives exactly `4 * columns` moves of at most
+/// `max_step * (2 * columns + 1)`.
+fn trench_outline(rng: &mut Rng, columns: usize, max_step: i64) -> Vec<(Direction, i64)> {
+    // Spans from north to south, both ends differ from the ones of the previous column
+    let mut spans = vec![(0, rng.range(1..=max_step))];
+    while spans.len() < columns {
+        let (north, south) = *spans.last().unwrap();
+        let next_north = loop {
+            let y = rng.range(north - max_step..=south - 1);
+            if y != north {
+                break y;
+            }
+        };
+        let next_south = loop {
+            let y = rng.range(north.max(next_north) + 1..=south + max_step);
+            if y != south {
+                break y;
+            }
+        };
+        spans.push((next_north, next_south));
+    }
+
+    let vertical = |from: i64, to: i64| {
+        let direction = if to < from {
+            Direction::North
+        } else {
+            Direction::South
+        };
+        (direction, from.abs_diff(to) as i64)
+    };
+    let mut moves = Vec::with_capacity(4 * columns);
+    for (i, (north, _)) in spans.iter().enumerate() {
+        moves.push((Direction::East, rng.range(1..=max_step)));
+        if let Some((next_north, _)) = spans.get(i + 1) {
+            moves.push(vertical(*north, *next_north));
+        }
+    }
+    let (last_north, last_south) = spans[columns - 1];
+    moves.push(vertical(last_north, last_south));
+    // Same widths on the way back, so the outline closes
+    let widths: Vec<i64> = moves
+        .iter()
+        .filter(|(direction, _)| *direction == Direction::East)
+        .map(|(_, length)| *length)
+        .collect();
+    for i in (0..columns).rev() {
+        moves.push((Direction::West, widths[i]));
+        if i > 0 {
+            moves.push(vertical(spans[i].1, spans[i - 1].1));
+        }
+    }
+    moves.push(vertical(spans[0].1, spans[0].0));
+    moves
+}
+
+/// `size` workflows forming a tree from `in`, and a third as many parts.
+fn workflows(rng: &mut Rng, size: usize) -> String {
+    let mut names = HashSet::from(["in".to_string()]);
+    let mut queue = VecDeque::from(["in".to_string()]);
+    let mut lines = Vec::new();
+    while let Some(name) = queue.pop_front() {
+        // Each rule checks another category, so that a workflow does not reject every part
+        let mut categories = ['x', 'm', 'a', 's'];
+        rng.shuffle(&mut categories);
+        let destinations: Vec<String> = (0..rng.range(2..=4))
+            .map(|_| {
+                if names.len() < size && rng.chance(0.7) {
+                    let length = rng.range(2..=3) as usize;
+                    let workflow = unique_name(rng, &mut names, length);
+                    queue.push_back(workflow.clone());
+                    workflow
+                } else if rng.chance(0.5) {
+                    "A".to_string()
+                } else {
+                    "R".to_string()
+                }
+            })
+            .collect();
+        let (fallback, destinations) = destinations.split_last().unwrap();
+        let mut rules = Vec::new();
+        for (category, destination) in categories.iter().zip(destinations) {
+            let condition = *rng.choose(&['<', '>']);
+            let value = rng.range(1..=4000);
+            rules.push(format!("{category}{condition}{value}:{destination}"));
+        }
+        rules.push(fallback.clone());
+        lines.push(format!("{name}{{{}}}", rules.join(",")));
+    }
+    rng.shuffle(&mut lines);
+
+    let mut input = lines.join("\n");
+    input.push_str("\n\n");
+    for _ in 0..(size / 3).max(1) {
+        let [x, m, a, s] = [(); 4].map(|_| rng.range(1..=4000));
+        writeln!(input, "{{x={x},m={m},a={a},s={s}}}").unwrap();
+    }
+    input
+}
+
+/// Four binary counters of `size` flip-flops (2 to 16). Each counter resets once it reaches its
+/// own cycle length, when its conjunction (`jj`, `gf`, `xz` or `bz`) sends a low pulse, which is
+/// inverted toward `rx`. Returns the cycle length of each counter.
+fn counter_network(rng: &mut Rng, size: usize) -> (String, Vec<u64>) {
+    const COUNTERS: [&str; 4] = ["jj", "gf", "xz", "bz"];
+    let bits = size.clamp(2, 16);
+    let mut names = HashSet::from(["broadcaster", "rx"].map(String::from));
+    names.extend(COUNTERS.map(String::from));
+    let output = unique_name(rng, &mut names, 2);
+
+    let mut lines = vec![format!("&{output} -> rx")];
+    let mut starts = Vec::new();
+    let mut cycles = Vec::new();
+    for counter in COUNTERS {
+        // Odd with the highest bit set, as the counter restarts from its first flip-flop
+        let cycle = rng.range(1 << (bits - 1)..=(1 << bits) - 1) as u64 | 1;
+        let flip_flops: Vec<String> = (0..bits).map(|_| unique_name(rng, &mut names, 2)).collect();
+        let inverter = unique_name(rng, &mut names, 2);
+        let mut counter_outputs = vec![inverter.clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs: Vec<&str> = flip_flops
+                .get(bit + 1)
+                .map(String::as_str)
+                .into_iter()
+                .collect();
+            if cycle >> bit & 1 == 1 {
+                outputs.push(counter);
+            }
+            if cycle >> bit & 1 == 0 || bit == 0 {
+                counter_outputs.push(flip_flop.clone());
+            }
+            rng.shuffle(&mut outputs);
+            lines.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+        rng.shuffle(&mut counter_outputs);
+        lines.push(format!("&{counter} -> {}", counter_outputs.join(", ")));
+        lines.push(format!("&{inverter} -> {output}"));
+        starts.push(flip_flops[0].clone());
+        cycles.push(cycle);
+    }
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut lines);
+    (format!("{}\n", lines.join("\n")), cycles)
+}
+
+/// Square map of odd side `size` (at least 5) with the start at its center. The borders and the
+/// center lines are clear and every plot can be reached.
+fn garden_map(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(5) | 1;
+    let center = side / 2;
+    let map = Grid::from_fn(side, side, |x, y| {
+        let clear = [x, y].iter().any(|i| [0, center, side - 1].contains(i));
+        if !clear && rng.chance(0.12) {
+            '#'
+        } else {
+            '.'
+        }
+    });
+
+    let mut reached = Grid::new(side, side, false);
+    reached[(center, center)] = true;
+    let mut stack = vec![(center, center)];
+    while let Some((x, y)) = stack.pop() {
+        for position in map.neighbours4(x, y) {
+            if map[position] == '.' && !reached[position] {
+                reached[position] = true;
+                stack.push(position);
+            }
+        }
+    }
+    let mut map = reached.map(|reached| if *reached { '.' } else { '#' });
+    map[(center, center)] = 'S';
+    map.to_string()
+}
+
+/// `size` bricks of up to 4 cubes over a 10 by 10 area.
+fn brick_snapshot(rng: &mut Rng, size: usize) -> String {
+    let mut occupied = HashSet::new();
+    let mut lines = Vec::new();
+    for i in 0..size {
+        let extra = rng.below(4);
+        let (dx, dy, dz) = match rng.below(10) {
+            0..=4 => (extra, 0, 0),
+            5..=8 => (0, extra, 0),
+            _ => (0, 0, extra),
+        };
+        let (x, y) = (rng.below(10 - dx), rng.below(10 - dy));
+        let cubes = |z: usize| {
+            (0..=extra).map(move |k| {
+                let k = [dx, dy, dz].map(|d| d.min(k));
+                (x + k[0], y + k[1], z + k[2])
+            })
+        };
+        let mut z = rng.below(i / 4 + 1) + 1;
+        while cubes(z).any(|cube| occupied.contains(&cube)) {
+            z += 1;
+        }
+        occupied.extend(cubes(z));
+        lines.push(format!("{x},{y},{z}~{},{},{}", x + dx, y + dy, z + dz));
+    }
+    rng.shuffle(&mut lines);
+    format!("{}\n", lines.join("\n"))
+}
+
+/// Maze of `size` by `size` crossings (at least 2) linked by corridors going east or south, with
+/// slopes around each crossing.
+fn hiking_trails(rng: &mut Rng, size: usize) -> String {
+    let crossings = size.max(2);
+    let mut positions = || {
+        let mut positions = vec![1];
+        for i in 1..crossings {
+            positions.push(positions[i - 1] + rng.range(4..=24) as usize);
+        }
+        positions
+    };
+    let (columns, rows) = (positions(), positions());
+    let (width, height) = (columns[crossings - 1] + 2, rows[crossings - 1] + 2);
+
+    let mut map = Grid::new(width, height, '#');
+    map[(1, 0)] = '.';
+    map[(width - 2, height - 1)] = '.';
+    for (j, &y) in rows.iter().enumerate() {
+        for (i, &x) in columns.iter().enumerate() {
+            map[(x, y)] = '.';
+            // The first row and the last column are kept so the end can always be reached
+            if i + 1 < crossings && (j == 0 || rng.chance(0.8)) {
+                let next = columns[i + 1];
+                for corridor_x in x + 1..next {
+                    let slope = corridor_x == x + 1 || corridor_x == next - 1;
+                    map[(corridor_x, y)] = if slope { '>' } else { '.' };
+                }
+            }
+            if j + 1 < crossings && (i + 1 == crossings || rng.chance(0.8)) {
+                let next = rows[j + 1];
+                for corridor_y in y + 1..next {
+                    let slope = corridor_y == y + 1 || corridor_y == next - 1;
+                    map[(x, corridor_y)] = if slope { 'v' } else { '.' };
+                }
+            }
+        }
+    }
+    map.to_string()
+}
+
+/// `size` hailstones (at least 3), each hit at a different integer time by a rock thrown from
+/// the returned position at the returned integer velocity.
+fn hailstorm(rng: &mut Rng, size: usize) -> (String, [Point3<i64>; 2]) {
+    let rock = Point3::new(
+        rng.range(100_000_000_000_000..=400_000_000_000_000),
+        rng.range(100_000_000_000_000..=400_000_000_000_000),
+        rng.range(100_000_000_000_000..=400_000_000_000_000),
+    );
+    let mut random_velocity = || {
+        Point3::new(
+            rng.range(-300..=300),
+            rng.range(-300..=300),
+            rng.range(-300..=300),
+        )
+    };
+    let rock_velocity = random_velocity();
+    // Hailstones with the same velocity would be parallel
+    let mut velocities = HashSet::from([rock_velocity]);
+    let mut stones = Vec::new();
+    while stones.len() < size.max(3) {
+        let velocity = random_velocity();
+        if velocity.x != 0 && velocity.y != 0 && velocities.insert(velocity) {
+            stones.push(velocity);
+        }
+    }
+
+    let mut times = HashSet::new();
+    let mut input = String::new();
+    for velocity in stones {
+        let time = loop {
+            let time = rng.range(100_000_000_000..=1_000_000_000_000);
+            if times.insert(time) {
+                break time;
+            }
+        };
+        let position = Point3::new(
+            rock.x + (rock_velocity.x - velocity.x) * time,
+            rock.y + (rock_velocity.y - velocity.y) * time,
+            rock.z + (rock_velocity.z - velocity.z) * time,
+        );
+        writeln!(
+            input,
+            "{}, {}, {} @ {}, {}, {}",
+            position.x, position.y, position.z, velocity.x, velocity.y, velocity.z
+        )
+        .unwrap();
+    }
+    (input, [rock, rock_velocity])
+}
+
+/// `size` components (at least 10) in two halves only linked by three wires.
+fn wiring_diagram(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(10);
+    let mut used = HashSet::new();
+    let names: Vec<String> = (0..size).map(|_| unique_name(rng, &mut used, 3)).collect();
+
+    // Each half is a ring also linked two components away, so at least 4 wires must be cut to
+    // split it, plus a few random wires
+    let half = size / 2;
+    let mut wires = HashSet::new();
+    for (start, length) in [(0, half), (half, size - half)] {
+        for i in 0..length {
+            for offset in 1..=2 {
+                let j = (i + offset) % length;
+                wires.insert((start + i.min(j), start + i.max(j)));
+            }
+        }
+        for _ in 0..length / 2 {
+            let (i, j) = (rng.below(length), rng.below(length));
+            if i != j {
+                wires.insert((start + i.min(j), start + i.max(j)));
+            }
+        }
+    }
+    let mut left: Vec<usize> = (0..half).collect();
+    let mut right: Vec<usize> = (half..size).collect();
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+    wires.extend((0..3).map(|i| (left[i], right[i])));
+
+    // Each wire is listed on the line of one of its components
+    let mut wires: Vec<_> = wires.into_iter().collect();
+    wires.sort();
+    let mut lines: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for (a, b) in wires {
+        let (start, end) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        lines.entry(start).or_default().push(&names[end]);
+    }
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|(start, mut ends)| {
+            rng.shuffle(&mut ends);
+            format!("{}: {}", names[start], ends.join(" "))
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    format!("{}\n", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests_generate {
+    use super::*;
+    use crate::days;
+    use common::{Part, Solution};
+
+    /// Sizes small enough for every day to be solved quickly in debug builds.
+    const TEST_SIZES: [usize; 25] = [
+        20, 10, 20, 20, 5, 2, 30, 3, 10, 12, 20, 20, 10, 10, 50, 10, 12, 20, 20, 4, 13, 30, 3, 5,
+        20,
+    ];
+
+    #[test]
+    fn test_deterministic() {
+        for day in 1..=25 {
+            let size = Some(TEST_SIZES[day as usize - 1]);
+            assert_eq!(generate(day, 7, size), generate(day, 7, size));
+        }
+        assert_ne!(generate(3, 1, Some(20)), generate(3, 2, Some(20)));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        for day in 1..=25 {
+            let solution = days::solution(day).unwrap();
+            for seed in 0..3 {
+                let input = generate(day, seed, Some(TEST_SIZES[day as usize - 1]));
+                let parsed = solution
+                    .parse_dyn(&input)
+                    .unwrap_or_else(|err| panic!("day {day} seed {seed}: {err}\n{input}"));
+                for part in [Part::One, Part::Two] {
+                    solution.part_dyn(parsed.as_ref(), part);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_expected_answers() {
+        let lcm = |a: u64, b: u64| {
+            let (mut x, mut y) = (a, b);
+            while y != 0 {
+                (x, y) = (y, x % y);
+            }
+            a / x * b
+        };
+        for seed in 0..3 {
+            let (input, steps) = ghost_network(&mut Rng::new(seed), 4);
+            let solution = days::solution(8).unwrap();
+            let network = solution.parse_dyn(&input).unwrap();
+            assert_eq!(
+                solution.part_dyn(network.as_ref(), Part::One),
+                steps[0].into()
+            );
+            assert_eq!(
+                solution.part_dyn(network.as_ref(), Part::Two),
+                steps.into_iter().reduce(lcm).unwrap().into()
+            );
+
+            let (input, cycles) = counter_network(&mut Rng::new(seed), 5);
+            let solution = days::solution(20).unwrap();
+            let modules = solution.parse_dyn(&input).unwrap();
+            assert_eq!(
+                solution.part_dyn(modules.as_ref(), Part::Two),
+                cycles.into_iter().reduce(lcm).unwrap().into()
+            );
+
+            let (input, [rock, velocity]) = hailstorm(&mut Rng::new(seed), 5);
+            let mut times = HashSet::new();
+            for path in day_24::Day24::default().parse(&input).unwrap() {
+                let (p, v) = (path.point, path.v0);
+                let (distance, speed) = [
+                    (rock.x - p.0, v.0 - velocity.x),
+                    (rock.y - p.1, v.1 - velocity.y),
+                    (rock.z - p.2, v.2 - velocity.z),
+                ]
+                .into_iter()
+                .find(|(_, speed)| *speed != 0)
+                .unwrap();
+                let time = distance / speed;
+                assert!(time > 0 && times.insert(time));
+                assert_eq!(
+                    (p.0 + v.0 * time, p.1 + v.1 * time, p.2 + v.2 * time),
+                    (
+                        rock.x + velocity.x * time,
+                        rock.y + velocity.y * time,
+                        rock.z + velocity.z * time
+                    )
+                );
+            }
+        }
+
+        let solution = days::solution(25).unwrap();
+        let diagram = solution.parse_dyn(&generate(25, 1, Some(21))).unwrap();
+        assert_eq!(
+            solution.part_dyn(diagram.as_ref(), Part::One),
+            (10 * 11).into()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_differential {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_day_05() {
+        for seed in 0..50 {
+            let input = almanac_below(&mut Rng::new(seed), 1 + seed as usize % 8, 2000);
+            let almanac = day_05::Day05.parse(&input).unwrap();
+            assert_eq!(
+                day_05::Day05.part_2(&almanac),
+                day_05::reference::part_2(&almanac).into(),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_day_08() {
+        for seed in 0..20 {
+            let (input, _) = ghost_network(&mut Rng::new(seed), 1 + seed as usize % 2);
+            let network = day_08::Day08.parse(&input).unwrap();
+            assert_eq!(
+                day_08::Day08.part_2(&network),
+                day_08::reference::part_2(&network).into(),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_day_21() {
+        for seed in 0..10 {
+            let mut rng = Rng::new(seed);
+            let map = day_21::Day21::default()
+                .parse(&garden_map(&mut rng, 13))
+                .unwrap();
+            for _ in 0..3 {
+                let steps = rng.range(26..=130) as u64;
+                assert_eq!(
+                    day_21::count_plots_extrapolated(&map, steps, 0),
+                    day_21::reference::part_2(&map, steps),
+                    "seed {seed}, {steps} steps"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_day_24() {
+        for seed in 0..20 {
+            let (input, _) = hailstorm(&mut Rng::new(seed), 3 + seed as usize % 5);
+            let paths = day_24::Day24::default().parse(&input).unwrap();
+            assert_eq!(
+                day_24::Day24::default().part_2(&paths),
+                day_24::reference::part_2(&paths).into(),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::Part;
+
+use crate::client::Verdict;
+use crate::inputs_dir;
+
+const HEADER: &str = "# Answers submitted with `aoc submit`: unix time, part, verdict, answer\n";
+
+/// Path of the submission log of `day`, next to its input.
+pub fn log_path(day: u8) -> PathBuf {
+    inputs_dir()
+        .join(format!("day-{day:02}"))
+        .join("submissions.log")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+impl Submission {
+    /// Submission of `answer` made now.
+    pub fn now(part: Part, answer: &str, verdict: Verdict) -> Self {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+        Self {
+            time,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        }
+    }
+
+    fn to_line(&self) -> String {
+        let verdict = match self.verdict {
+            Verdict::Correct => "correct".to_string(),
+            Verdict::TooHigh => "too-high".to_string(),
+            Verdict::TooLow => "too-low".to_string(),
+            Verdict::Incorrect => "incorrect".to_string(),
+            Verdict::Wait(left) => format!("wait-{}", left.as_secs()),
+            Verdict::WrongLevel => "wrong-level".to_string(),
+        };
+        format!(
+            "{}\t{}\t{verdict}\t{}\n",
+            self.time,
+            self.part.number(),
+            self.answer
+        )
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(4, '\t');
+        let time = fields.next()?.parse().ok()?;
+        let part = match fields.next()? {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return None,
+        };
+        let verdict = match fields.next()? {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "incorrect" => Verdict::Incorrect,
+            "wrong-level" => Verdict::WrongLevel,
+            verdict => Verdict::Wait(Duration::from_secs(
+                verdict.strip_prefix("wait-")?.parse().ok()?,
+            )),
+        };
+        let answer = fields.next()?.to_string();
+        Some(Self {
+            time,
+            part,
+            verdict,
+            answer,
+        })
+    }
+}
+
+/// Every answer submitted for a day, oldest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Reads the log at `path`, a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}:{err}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("could not read `{}`: {err}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut history = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let submission = Submission::parse(line)
+                .ok_or_else(|| format!("{}: invalid submission `{line}`", index + 1))?;
+            history.submissions.push(submission);
+        }
+        Ok(history)
+    }
+
+    /// Adds `submission` to the history and to the end of the log at `path`.
+    pub fn append(&mut self, path: &Path, submission: Submission) -> Result<(), String> {
+        let error = |err: std::io::Error| format!("could not write `{}`: {err}", path.display());
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(error)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(error)?;
+        let mut text = submission.to_line();
+        if file.metadata().map_err(error)?.len() == 0 {
+            text.insert_str(0, HEADER);
+        }
+        file.write_all(text.as_bytes()).map_err(error)?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Why submitting `answer` to `part` is known to be pointless, if it is.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), String> {
+        let submissions = self.submissions.iter().filter(|s| s.part == part);
+        let mut too_high: Option<i128> = None;
+        let mut too_low: Option<i128> = None;
+        for submission in submissions {
+            let rejected = match submission.verdict {
+                Verdict::Correct => {
+                    return Err(format!(
+                        "part {} was already solved with `{}`",
+                        part.number(),
+                        submission.answer
+                    ))
+                }
+                Verdict::TooHigh => "too high",
+                Verdict::TooLow => "too low",
+                Verdict::Incorrect => "not the right answer",
+                Verdict::Wait(_) | Verdict::WrongLevel => continue,
+            };
+            if submission.answer == answer {
+                return Err(format!("`{answer}` was already rejected as {rejected}"));
+            }
+            if let Ok(value) = submission.answer.parse::<i128>() {
+                match submission.verdict {
+                    Verdict::TooHigh => {
+                        too_high = Some(too_high.map_or(value, |high| high.min(value)))
+                    }
+                    Verdict::TooLow => too_low = Some(too_low.map_or(value, |low| low.max(value))),
+                    _ => {}
+                }
+            }
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        match (too_low, too_high) {
+            (_, Some(high)) if value >= high => Err(format!(
+                "`{answer}` is too high, `{high}` already was{}",
+                bounds(too_low, too_high)
+            )),
+            (Some(low), _) if value <= low => Err(format!(
+                "`{answer}` is too low, `{low}` already was{}",
+                bounds(too_low, too_high)
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// `, the answer is between 10 and 20` from the closest rejected answers.
+fn bounds(too_low: Option<i128>, too_high: Option<i128>) -> String {
+    match (too_low, too_high) {
+        (Some(low), Some(high)) => format!(", the answer is between {} and {}", low + 1, high - 1),
+        (Some(low), None) => format!(", the answer is at least {}", low + 1),
+        (None, Some(high)) => format!(", the answer is at most {}", high - 1),
+        (None, None) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests_history {
+    use super::*;
+
+    fn history(submissions: &[(Part, &str, Verdict)]) -> History {
+        History {
+            submissions: submissions
+                .iter()
+                .enumerate()
+                .map(|(time, (part, answer, verdict))| Submission {
+                    time: time as u64,
+                    part: *part,
+                    verdict: *verdict,
+                    answer: answer.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let history = history(&[
+            (Part::One, "150", Verdict::TooHigh),
+            (Part::One, "142", Verdict::Wait(Duration::from_secs(42))),
+            (Part::One, "142", Verdict::Correct),
+            (Part::Two, "two\twords", Verdict::Incorrect),
+            (Part::Two, "281", Verdict::WrongLevel),
+        ]);
+        let path = std::env::temp_dir()
+            .join(format!("aoc-history-{}", std::process::id()))
+            .join("submissions.log");
+        let mut loaded = History::load(&path).unwrap();
+        for submission in &history.submissions {
+            loaded.append(&path, submission.clone()).unwrap();
+        }
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.starts_with(&format!(
+            "{HEADER}0\t1\ttoo-high\t150\n1\t1\twait-42\t142\n"
+        )));
+        assert_eq!(History::load(&path), Ok(history.clone()));
+        assert_eq!(loaded, history);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(
+            History::parse("# log\n\n1\t3\tcorrect\t5\n"),
+            Err("3: invalid submission `1\t3\tcorrect\t5`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let history = history(&[
+            (Part::One, "abc", Verdict::Incorrect),
+            (Part::One, "500", Verdict::TooHigh),
+            (Part::One, "100", Verdict::TooLow),
+            (Part::One, "300", Verdict::TooHigh),
+            (Part::One, "200", Verdict::Wait(Duration::from_secs(30))),
+            (Part::Two, "7", Verdict::Correct),
+        ]);
+        assert_eq!(history.check(Part::One, "200"), Ok(()));
+        assert_eq!(history.check(Part::One, "def"), Ok(()));
+        assert_eq!(
+            history.check(Part::One, "abc"),
+            Err("`abc` was already rejected as not the right answer".to_string())
+        );
+        assert_eq!(
+            history.check(Part::One, "500"),
+            Err("`500` was already rejected as too high".to_string())
+        );
+        assert_eq!(
+            history.check(Part::One, "400"),
+            Err(
+                "`400` is too high, `300` already was, the answer is between 101 and 299"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            history.check(Part::One, "-5"),
+            Err(
+                "`-5` is too low, `100` already was, the answer is between 101 and 299".to_string()
+            )
+        );
+        assert_eq!(
+            history.check(Part::Two, "8"),
+            Err("part 2 was already solved with `7`".to_string())
+        );
+        assert_eq!(History::default().check(Part::Two, "8"), Ok(()));
+    }
+}
@@ -0,0 +1,226 @@
+mod alloc_stats;
+mod answers;
+mod bench;
+mod client;
+mod days;
+mod examples;
+mod fixtures;
+mod generate;
+mod history;
+mod new;
+mod run_all;
+mod verify;
+mod watch;
+
+use common::{normalize_input, Answer, Part};
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "Usage:
+    aoc run <day> [--part 1|2] [--input PATH|-] [--set NAME=VALUE...]
+    aoc run --all [--part 1|2] [--jobs N] [--timeout SECS]
+    aoc bench [<day>...] [--iterations N] [--json PATH] [--csv PATH]
+    aoc verify [<day>...] [--record]
+    aoc test [<day>...]
+    aoc watch <day> [--interval MS]
+    aoc new <day>
+    aoc gen <day> [--seed N] [--size N]
+    aoc examples <day> <page.html>
+    aoc download <day>... [--force]
+    aoc submit <day> <1|2> [<answer>] [--force]
+
+Options:
+    --part 1|2          Only run one part of the day (default: both)
+    --input PATH        Read the puzzle input from PATH, `-` reads from stdin
+                        (default: aoc-2023-inputs/day-XX/input.txt)
+    --set NAME=VALUE    Change a parameter of the day, such as `expansion=10` for day 11 or
+                        `vocabulary=PATH` to read the digit tokens of day 1 from a file
+    --all               Run every day and print a summary table
+    --jobs N            Number of days run at the same time (default: number of CPUs)
+    --timeout SECS      Stop waiting for the days still running after SECS seconds (default: 60)
+    --iterations N      Number of timed runs of each day (default: 10)
+    --json PATH         Write the benchmark report as JSON to PATH
+    --csv PATH          Write the benchmark report as CSV to PATH
+    --record            Save the answers missing from aoc-2023-inputs/answers.toml
+    --seed N            Seed of the generated input (default: 0)
+    --size N            Size of the generated input, its meaning depends on the day
+                        (default: about the size of a real input)
+    --interval MS       Time between two checks of the files watched (default: 500)
+    --force             Download the input again even if it is already there, or submit an
+                        answer the submission log knows to be wrong
+
+Without days, `aoc bench` and `aoc verify` go through every solved day that has an input.
+`aoc test` checks the example answers listed in fixtures.txt. `aoc watch` runs the tests and the
+solution of a day again whenever its sources or inputs change.
+`aoc examples` saves the examples of a saved puzzle page as test*.txt inputs and adds tests of
+their answers to the day crate.
+`aoc download` and `aoc submit` use the session cookie in `AOC_SESSION`, `aoc submit` sends the
+answer of the solution when none is given and keeps a log of the submissions in
+aoc-2023-inputs/day-XX/submissions.log. Set `AOC_URL` to use another server, such as the
+`mock-server` of the workspace.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench::bench(&args[1..]),
+        Some("verify") => verify::verify(&args[1..]),
+        Some("test") => fixtures::test(&args[1..]),
+        Some("watch") => watch::watch(&args[1..]),
+        Some("new") => new::new(&args[1..]),
+        Some("gen") => generate::gen(&args[1..]),
+        Some("examples") => examples::examples(&args[1..]),
+        Some("download") => client::download(&args[1..]),
+        Some("submit") => client::submit(&args[1..]),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(command) => Err(format!("unknown command `{command}`")),
+        None => Err("missing command".to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            eprintln!("Run `aoc --help` for usage.");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut day = None;
+    let mut parts = vec![Part::One, Part::Two];
+    let mut input_path = None;
+    let mut parameters = Vec::new();
+    let mut all = false;
+    let mut jobs = None;
+    let mut timeout = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args.next().ok_or("missing value for `--part`")?;
+                parts = match part.as_str() {
+                    "1" => vec![Part::One],
+                    "2" => vec![Part::Two],
+                    _ => return Err(format!("invalid part `{part}`, expected 1 or 2")),
+                };
+            }
+            "--input" => {
+                input_path = Some(args.next().ok_or("missing value for `--input`")?.clone());
+            }
+            "--set" => {
+                let value = args.next().ok_or("missing value for `--set`")?;
+                let (name, value) = value
+                    .split_once('=')
+                    .ok_or(format!("invalid parameter `{value}`, expected NAME=VALUE"))?;
+                parameters.push((name.to_string(), value.to_string()));
+            }
+            "--all" => all = true,
+            "--timeout" => {
+                let value = args.next().ok_or("missing value for `--timeout`")?;
+                timeout = match value.parse() {
+                    Ok(seconds @ 1..) => Some(Duration::from_secs(seconds)),
+                    _ => {
+                        return Err(format!(
+                            "invalid timeout `{value}`, expected a positive number of seconds"
+                        ))
+                    }
+                };
+            }
+            "--jobs" => {
+                let value = args.next().ok_or("missing value for `--jobs`")?;
+                jobs = match value.parse() {
+                    Ok(jobs @ 1..) => Some(jobs),
+                    _ => {
+                        return Err(format!(
+                            "invalid jobs `{value}`, expected a positive number"
+                        ))
+                    }
+                };
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    if all {
+        if day.is_some() || input_path.is_some() || !parameters.is_empty() {
+            return Err("`--all` cannot be combined with a day, `--input` or `--set`".to_string());
+        }
+        let jobs = jobs
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |jobs| jobs.get()));
+        let timeout = timeout.unwrap_or(Duration::from_secs(60));
+        return run_all::run_all(&parts, jobs, timeout);
+    }
+    if jobs.is_some() || timeout.is_some() {
+        return Err("`--jobs` and `--timeout` only apply to `--all`".to_string());
+    }
+    let day = day.ok_or("missing day")?;
+
+    let source = match input_path.as_deref() {
+        Some("-") => "stdin".to_string(),
+        Some(path) => path.to_string(),
+        None => default_input_path(day).display().to_string(),
+    };
+    let input = match input_path.as_deref() {
+        Some("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| format!("could not read input from stdin: {err}"))?;
+            normalize_input(&input)
+        }
+        Some(path) => read_input(PathBuf::from(path))?,
+        None => read_input(default_input_path(day))?,
+    };
+
+    let solution = days::solution_with(day, &parameters)?;
+    let (parsed, stats) = alloc_stats::measure(|| solution.parse_dyn(&input));
+    let parsed =
+        parsed.map_err(|err| format!("invalid input `{source}`: {}", err.diagnostic(&input)))?;
+    if let Some(stats) = stats {
+        println!("Day {day:02} parse: {stats}");
+    }
+    for part in parts {
+        let number = part.number();
+        let (answer, stats) = alloc_stats::measure(|| solution.part_dyn(parsed.as_ref(), part));
+        let stats = stats.map_or(String::new(), |stats| format!(" ({stats})"));
+        match answer {
+            Answer::None => println!("Day {day:02} part {number}: no puzzle{stats}"),
+            answer => println!("Day {day:02} part {number}: {answer}{stats}"),
+        }
+    }
+    Ok(())
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    match arg.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!(
+            "invalid day `{arg}`, expected a number between 1 and 25"
+        )),
+    }
+}
+
+/// Directory holding the inputs, can be overridden with the `AOC_INPUTS` environment variable.
+fn inputs_dir() -> PathBuf {
+    match std::env::var_os("AOC_INPUTS") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc-2023-inputs")),
+    }
+}
+
+fn default_input_path(day: u8) -> PathBuf {
+    inputs_dir().join(format!("day-{day:02}")).join("input.txt")
+}
+
+fn read_input(path: PathBuf) -> Result<String, String> {
+    common::read_input(&path)
+        .map_err(|err| format!("could not read input `{}`: {err}", path.display()))
+}
@@ -0,0 +1,113 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use crate::{inputs_dir, parse_day};
+
+/// Files of `template-day`, `{{day}}` is replaced by the two digits day number.
+const TEMPLATE: [(&str, &str); 3] = [
+    ("Cargo.toml", include_str!("../../template-day/Cargo.toml")),
+    ("src/lib.rs", include_str!("../../template-day/src/lib.rs")),
+    (
+        "src/main.rs",
+        include_str!("../../template-day/src/main.rs"),
+    ),
+];
+
+/// Example cases of a new day appended to `fixtures.txt`, to fill with the expected answers.
+const FIXTURES: &str = include_str!("../../template-day/fixtures.txt");
+
+/// `aoc new <day>`
+pub fn new(args: &[String]) -> Result<(), String> {
+    let day = match args {
+        [day] => parse_day(day)?,
+        [] => return Err("missing day".to_string()),
+        [_, arg, ..] => return Err(format!("unexpected argument `{arg}`")),
+    };
+    let workspace = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    create_day(workspace, &inputs_dir(), day)?;
+    println!("New day: day-{day:02}");
+    println!("Register it in aoc/Cargo.toml and aoc/src/days.rs to run it with `aoc run {day}`.");
+    Ok(())
+}
+
+/// Creates the `day-XX` crate in `workspace`, its example cases in the fixtures manifest and its
+/// empty input files in `inputs`.
+pub fn create_day(workspace: &Path, inputs: &Path, day: u8) -> Result<(), String> {
+    let day_dir = workspace.join(format!("day-{day:02}"));
+    if day_dir.exists() {
+        return Err(format!("`{}` already exists", day_dir.display()));
+    }
+    for (file, template) in TEMPLATE {
+        let path = day_dir.join(file);
+        std::fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| std::fs::write(&path, render(template, day)))
+            .map_err(|err| format!("could not write `{}`: {err}", path.display()))?;
+    }
+    let manifest = workspace.join("fixtures.txt");
+    OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(&manifest)
+        .and_then(|mut file| file.write_all(render(FIXTURES, day).as_bytes()))
+        .map_err(|err| format!("could not write `{}`: {err}", manifest.display()))?;
+
+    let input_dir = inputs.join(format!("day-{day:02}"));
+    std::fs::create_dir_all(&input_dir)
+        .map_err(|err| format!("could not create `{}`: {err}", input_dir.display()))?;
+    for file in ["input.txt", "test.txt"] {
+        let path = input_dir.join(file);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(err) => return Err(format!("could not create `{}`: {err}", path.display())),
+        }
+    }
+    Ok(())
+}
+
+pub fn render(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &format!("{day:02}"))
+}
+
+#[cfg(test)]
+mod tests_new {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let lib = render(TEMPLATE[1].1, 7);
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(!lib.contains("{{"));
+        assert_eq!(render(FIXTURES, 7), "07 1 test.txt 0\n07 2 test.txt 0\n");
+        assert!(render(TEMPLATE[0].1, 7).contains("name = \"day-07\""));
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let (workspace, inputs) = (root.join("workspace"), root.join("inputs"));
+        std::fs::create_dir_all(inputs.join("day-09")).unwrap();
+        std::fs::write(inputs.join("day-09/input.txt"), "0 3 6").unwrap();
+
+        assert_eq!(create_day(&workspace, &inputs, 9), Ok(()));
+        assert!(workspace.join("day-09/src/main.rs").exists());
+        assert_eq!(
+            std::fs::read_to_string(workspace.join("fixtures.txt")).unwrap(),
+            "09 1 test.txt 0\n09 2 test.txt 0\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(inputs.join("day-09/input.txt")).unwrap(),
+            "0 3 6"
+        );
+        assert_eq!(
+            std::fs::read_to_string(inputs.join("day-09/test.txt")).unwrap(),
+            ""
+        );
+        assert!(create_day(&workspace, &inputs, 9)
+            .unwrap_err()
+            .ends_with("day-09` already exists"));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,337 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use common::{Answer, Part};
+
+use crate::{days, default_input_path, read_input};
+
+/// Outcome of one part of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Ok,
+    NoPuzzle,
+    NotSolved,
+    NoInput,
+    InvalidInput(String),
+    Panicked(String),
+    TimedOut,
+}
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::InvalidInput(_) | Status::Panicked(_) | Status::TimedOut
+        )
+    }
+}
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::NoPuzzle => write!(f, "no puzzle"),
+            Status::NotSolved => write!(f, "not solved"),
+            Status::NoInput => write!(f, "no input"),
+            Status::InvalidInput(err) => write!(f, "invalid input: {err}"),
+            Status::Panicked(message) => write!(f, "panicked: {message}"),
+            Status::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub time: Option<Duration>,
+    pub status: Status,
+}
+
+/// Messages sent by the workers while they go through the days.
+enum Event {
+    Started(u8),
+    Solved(Report),
+    Finished(u8),
+}
+
+/// `aoc run --all [--part 1|2] [--jobs N] [--timeout SECS]`, runs every day on a pool of `jobs`
+/// threads. A day still running after `timeout` is reported as timed out and its worker is
+/// replaced, so that it does not hold the other days back.
+pub fn run_all(parts: &[Part], jobs: usize, timeout: Duration) -> Result<(), String> {
+    let next_day = Arc::new(AtomicU8::new(1));
+    let (sender, receiver) = mpsc::channel();
+    let spawn_worker = || {
+        let (next_day, sender, parts) = (next_day.clone(), sender.clone(), parts.to_vec());
+        // Never joined, a day that does not end must not keep the summary from being printed
+        std::thread::spawn(move || loop {
+            let day = next_day.fetch_add(1, Ordering::Relaxed);
+            if day > 25 {
+                break;
+            }
+            let _ = sender.send(Event::Started(day));
+            run_day(day, &parts, |report| {
+                let _ = sender.send(Event::Solved(report));
+            });
+            let _ = sender.send(Event::Finished(day));
+        });
+    };
+
+    // Panics are reported in the table, keep them from being printed as they happen
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    (0..jobs.min(25)).for_each(|_| spawn_worker());
+    let mut running: HashMap<u8, Instant> = HashMap::new();
+    let mut finished = 0;
+    let mut reports: Vec<Report> = Vec::new();
+    while finished < 25 {
+        let event = match running.values().min() {
+            Some(started) => receiver
+                .recv_timeout((*started + timeout).saturating_duration_since(Instant::now())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match event {
+            Ok(Event::Started(day)) => {
+                running.insert(day, Instant::now());
+            }
+            Ok(Event::Solved(report)) if running.contains_key(&report.day) => reports.push(report),
+            Ok(Event::Solved(_)) => {}
+            Ok(Event::Finished(day)) => finished += running.remove(&day).map_or(0, |_| 1),
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let timed_out: Vec<u8> = running
+                    .iter()
+                    .filter(|(_, started)| now.duration_since(**started) >= timeout)
+                    .map(|(day, _)| *day)
+                    .collect();
+                for day in timed_out {
+                    running.remove(&day);
+                    finished += 1;
+                    let missing: Vec<Part> = parts
+                        .iter()
+                        .filter(|part| !reports.iter().any(|r| r.day == day && r.part == **part))
+                        .copied()
+                        .collect();
+                    unsolved(day, &missing, Status::TimedOut, |report| {
+                        reports.push(report)
+                    });
+                    spawn_worker();
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("a sender is kept for new workers"),
+        }
+    }
+    let wall_time = start.elapsed();
+    std::panic::set_hook(hook);
+
+    reports.sort_by_key(|report| (report.day, report.part.number()));
+    print!("{}", format_table(&reports));
+    let total: Duration = reports.iter().filter_map(|report| report.time).sum();
+    let failed = reports
+        .iter()
+        .filter(|report| report.status.is_failure())
+        .count();
+    println!(
+        "Total: {total:.2?} over {} parts, {wall_time:.2?} wall clock with {jobs} threads",
+        reports
+            .iter()
+            .filter(|report| report.time.is_some())
+            .count()
+    );
+
+    match failed {
+        0 => Ok(()),
+        1 => Err("1 part failed".to_string()),
+        failed => Err(format!("{failed} parts failed")),
+    }
+}
+
+/// Reads the input of `day` then solves `parts`, the time of a part does not include parsing.
+fn run_day(day: u8, parts: &[Part], report: impl FnMut(Report)) {
+    if days::solution(day).is_none() {
+        return unsolved(day, parts, Status::NotSolved, report);
+    }
+    let path = default_input_path(day);
+    if !path.exists() {
+        return unsolved(day, parts, Status::NoInput, report);
+    }
+    match read_input(path) {
+        Ok(input) => solve_day(day, &input, parts, report),
+        Err(err) => unsolved(day, parts, Status::InvalidInput(err), report),
+    }
+}
+
+/// Parses `input` then solves `parts` of `day`, reporting each part as soon as it is solved. A
+/// panic only fails the parts it happens in.
+pub fn solve_day(day: u8, input: &str, parts: &[Part], mut report: impl FnMut(Report)) {
+    let solution = days::solution(day).expect("day is solved");
+    let parsed = match catch_panic(|| solution.parse_dyn(input)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(err)) => return unsolved(day, parts, Status::InvalidInput(err.to_string()), report),
+        Err(message) => return unsolved(day, parts, Status::Panicked(message), report),
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let result = catch_panic(|| solution.part_dyn(parsed.as_ref(), *part));
+        let time = start.elapsed();
+        let (answer, status) = match result {
+            Ok(Answer::None) => (None, Status::NoPuzzle),
+            Ok(answer) => (Some(answer), Status::Ok),
+            Err(message) => (None, Status::Panicked(message)),
+        };
+        report(Report {
+            day,
+            part: *part,
+            answer,
+            time: Some(time),
+            status,
+        });
+    }
+}
+
+/// Reports every part with the same `status`, none of them was solved.
+fn unsolved(day: u8, parts: &[Part], status: Status, mut report: impl FnMut(Report)) {
+    for part in parts {
+        report(Report {
+            day,
+            part: *part,
+            answer: None,
+            time: None,
+            status: status.clone(),
+        });
+    }
+}
+
+/// Runs `f`, turning a panic into its message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(payload.as_ref()))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Table with a line per report, columns padded to their widest value.
+pub fn format_table(reports: &[Report]) -> String {
+    let header = ["Day", "Part", "Answer", "Time", "Status"].map(String::from);
+    let rows: Vec<[String; 5]> = reports
+        .iter()
+        .map(|report| {
+            [
+                format!("{:02}", report.day),
+                report.part.number().to_string(),
+                report
+                    .answer
+                    .as_ref()
+                    .map_or("-".to_string(), Answer::to_string),
+                report
+                    .time
+                    .map_or("-".to_string(), |time| format!("{time:.2?}")),
+                report.status.to_string(),
+            ]
+        })
+        .collect();
+    let mut widths = header.clone().map(|column| column.len());
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests_run_all {
+    use super::*;
+    use crate::generate::generate;
+
+    #[test]
+    fn test_solve_day() {
+        let input = generate(1, 0, Some(20));
+        let solve = |day, input: &str, parts: &[Part]| {
+            let mut reports = Vec::new();
+            solve_day(day, input, parts, |report| reports.push(report));
+            reports
+        };
+        let reports = solve(1, &input, &[Part::One, Part::Two]);
+        let solution = days::solution(1).unwrap();
+        let parsed = solution.parse_dyn(&input).unwrap();
+        for (report, part) in reports.iter().zip([Part::One, Part::Two]) {
+            assert_eq!(report.part, part);
+            assert_eq!(report.status, Status::Ok);
+            assert_eq!(
+                report.answer,
+                Some(solution.part_dyn(parsed.as_ref(), part))
+            );
+        }
+
+        let reports = solve(25, &generate(25, 0, Some(20)), &[Part::Two]);
+        assert_eq!(reports[0].status, Status::NoPuzzle);
+        let reports = solve(5, "seeds: 79 14\n\n50 98\n", &[Part::One]);
+        assert!(reports[0].status.is_failure());
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("no rock hits every hailstone") }),
+            Err("no rock hits every hailstone".to_string())
+        );
+        let day = 7;
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("day {day} failed") }),
+            Err("day 7 failed".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_table() {
+        let reports = [
+            Report {
+                day: 1,
+                part: Part::One,
+                answer: Some(Answer::Number(54331)),
+                time: Some(Duration::from_micros(1500)),
+                status: Status::Ok,
+            },
+            Report {
+                day: 12,
+                part: Part::Two,
+                answer: None,
+                time: None,
+                status: Status::Panicked("overflow".to_string()),
+            },
+        ];
+        assert_eq!(
+            format_table(&reports),
+            "Day  Part  Answer  Time    Status\n\
+             01   1     54331   1.50ms  ok\n\
+             12   2     -       -       panicked: overflow\n"
+        );
+    }
+}
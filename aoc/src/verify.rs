@@ -0,0 +1,90 @@
+use std::time::Instant;
+
+use common::{Answer, Answers, Part};
+
+use crate::answers::manifest_path;
+use crate::{days, default_input_path, parse_day, read_input};
+
+/// `aoc verify [<day>...] [--record]`
+pub fn verify(args: &[String]) -> Result<(), String> {
+    let mut selected_days = Vec::new();
+    let mut record = false;
+    for arg in args {
+        match arg.as_str() {
+            "--record" => record = true,
+            _ => selected_days.push(parse_day(arg)?),
+        }
+    }
+    let explicit_days = !selected_days.is_empty();
+    if !explicit_days {
+        selected_days = (1..=25).collect();
+    }
+
+    let path = manifest_path();
+    let mut answers = Answers::load(&path)?;
+    let (mut passed, mut failed, mut missing, mut recorded) = (0, 0, 0, 0);
+    for day in selected_days {
+        let Some(solution) = days::solution(day) else {
+            if explicit_days {
+                return Err(format!("day {day} is not solved yet"));
+            }
+            continue;
+        };
+        let input_path = default_input_path(day);
+        if !input_path.exists() {
+            println!(
+                "Day {day:02}         skip  no input at `{}`",
+                input_path.display()
+            );
+            continue;
+        }
+        let input = read_input(input_path)?;
+        let parsed = match solution.parse_dyn(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                println!("Day {day:02}         FAIL  invalid input: {err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        for part in [Part::One, Part::Two] {
+            let now = Instant::now();
+            let answer = solution.part_dyn(parsed.as_ref(), part);
+            let elapsed = now.elapsed();
+            let label = format!("Day {day:02} part {}", part.number());
+            match (answers.get(day, part), answer) {
+                (_, Answer::None) => {}
+                (Some(expected), answer) if *expected == answer => {
+                    println!("{label}  pass  {answer} ({elapsed:.2?})");
+                    passed += 1;
+                }
+                (Some(expected), answer) => {
+                    println!("{label}  FAIL  expected {expected}, got {answer} ({elapsed:.2?})");
+                    failed += 1;
+                }
+                (None, answer) if record => {
+                    println!("{label}  new   {answer} ({elapsed:.2?}), recorded");
+                    answers.insert(day, part, answer);
+                    recorded += 1;
+                }
+                (None, answer) => {
+                    println!("{label}  miss  {answer} ({elapsed:.2?}), no expected answer");
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    if recorded > 0 {
+        answers.save(&path)?;
+    }
+    println!("\n{passed} passed, {failed} failed, {missing} missing, {recorded} recorded");
+    if failed > 0 {
+        return Err(format!(
+            "{failed} answers do not match `{}`",
+            path.display()
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+
+use crate::{inputs_dir, parse_day};
+
+/// Modification time and size of every file under the watched paths.
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+/// `aoc watch <day> [--interval MS]`, polls the sources and the inputs of the day and runs its
+/// tests and its solution again whenever one of them changes.
+pub fn watch(args: &[String]) -> Result<(), String> {
+    let mut day = None;
+    let mut interval = Duration::from_millis(500);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--interval" => {
+                let value = args.next().ok_or("missing value for `--interval`")?;
+                interval = match value.parse() {
+                    Ok(millis @ 1..) => Duration::from_millis(millis),
+                    _ => {
+                        return Err(format!(
+                            "invalid interval `{value}`, expected a positive number of milliseconds"
+                        ))
+                    }
+                };
+            }
+            _ if day.is_none() => day = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+
+    let workspace = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let crate_dir = workspace.join(format!("day-{day:02}"));
+    if !crate_dir.exists() {
+        return Err(format!("`{}` does not exist", crate_dir.display()));
+    }
+    let watched = [
+        crate_dir.join("src"),
+        crate_dir.join("Cargo.toml"),
+        inputs_dir().join(format!("day-{day:02}")),
+    ];
+
+    let mut answers = BTreeMap::new();
+    let mut last_snapshot = None;
+    loop {
+        let current = snapshot(&watched);
+        if last_snapshot.as_ref() != Some(&current) {
+            if last_snapshot.is_some() {
+                println!("\nChange detected, running day {day:02} again");
+            }
+            last_snapshot = Some(current);
+            if let Some(new_answers) = run_once(workspace, day) {
+                for line in diff(day, &answers, &new_answers) {
+                    println!("{line}");
+                }
+                answers = new_answers;
+            }
+            println!("Watching day {day:02}, press Ctrl+C to stop");
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+/// Files under `paths`, a missing path has no files.
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else {
+            snapshot.insert(path, (metadata.modified().ok(), metadata.len()));
+        }
+    }
+    snapshot
+}
+
+/// Runs the tests of the day then its solution, returns the answers when the solution ran.
+fn run_once(workspace: &Path, day: u8) -> Option<BTreeMap<u8, String>> {
+    let cargo = std::env::var_os("CARGO").unwrap_or("cargo".into());
+    let tests = Command::new(&cargo)
+        .args(["test", "-q", "-p", &format!("day-{day:02}")])
+        .current_dir(workspace)
+        .status();
+    match tests {
+        Ok(status) if status.success() => println!("Tests passed"),
+        Ok(_) => println!("Tests failed"),
+        Err(err) => println!("Could not run the tests: {err}"),
+    }
+
+    // Built apart so that the running `aoc` is not replaced
+    let target_dir = workspace.join("target").join("watch");
+    let run = Command::new(&cargo)
+        .args(["run", "-q", "--release", "-p", "aoc", "--target-dir"])
+        .arg(&target_dir)
+        .args(["--", "run", &day.to_string()])
+        .current_dir(workspace)
+        .stderr(Stdio::inherit())
+        .output();
+    match run {
+        Ok(output) if output.status.success() => {
+            Some(parse_answers(day, &String::from_utf8_lossy(&output.stdout)))
+        }
+        Ok(_) => {
+            println!("Solution failed, answers kept from the previous run");
+            None
+        }
+        Err(err) => {
+            println!("Could not run the solution: {err}");
+            None
+        }
+    }
+}
+
+/// Answers by part in the output of `aoc run <day>`.
+fn parse_answers(day: u8, output: &str) -> BTreeMap<u8, String> {
+    let prefix = format!("Day {day:02} part ");
+    output
+        .lines()
+        .filter_map(|line| line.strip_prefix(&prefix)?.split_once(": "))
+        .filter_map(|(part, answer)| Some((part.parse().ok()?, answer.to_string())))
+        .collect()
+}
+
+/// A line per part comparing its answer with the one of the previous run.
+fn diff(day: u8, previous: &BTreeMap<u8, String>, current: &BTreeMap<u8, String>) -> Vec<String> {
+    let mut parts: Vec<u8> = previous.keys().chain(current.keys()).copied().collect();
+    parts.sort();
+    parts.dedup();
+    parts
+        .into_iter()
+        .map(|part| {
+            let change = match (previous.get(&part), current.get(&part)) {
+                (Some(before), Some(now)) if before == now => format!("{now} (unchanged)"),
+                (Some(before), Some(now)) => format!("{now} (was {before})"),
+                (None, Some(now)) => format!("{now} (new)"),
+                (Some(before), None) => format!("no answer (was {before})"),
+                (None, None) => unreachable!("part comes from one of the runs"),
+            };
+            format!("Day {day:02} part {part}: {change}")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_watch {
+    use super::*;
+
+    #[test]
+    fn test_snapshot() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src/lib.rs"), "fn a() {}").unwrap();
+        let paths = [dir.join("src"), dir.join("missing")];
+
+        let before = snapshot(&paths);
+        assert_eq!(before.len(), 1);
+        assert_eq!(snapshot(&paths), before);
+        std::fs::write(dir.join("src/lib.rs"), "fn ab() {}").unwrap();
+        let after = snapshot(&paths);
+        assert_ne!(after, before);
+        std::fs::write(dir.join("src/part1.rs"), "").unwrap();
+        assert_ne!(snapshot(&paths), after);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(snapshot(&paths).is_empty());
+    }
+
+    #[test]
+    fn test_diff() {
+        let answers = parse_answers(
+            7,
+            "Day 07 parse: 3 allocations, 1.00 KiB allocated, 1.00 KiB peak\n\
+             Day 07 part 1: 6440\n\
+             Day 07 part 2: 5905\n\
+             Day 08 part 1: 6\n",
+        );
+        assert_eq!(
+            answers,
+            BTreeMap::from([(1, "6440".to_string()), (2, "5905".to_string())])
+        );
+
+        let previous = BTreeMap::from([(1, "6440".to_string()), (2, "5905".to_string())]);
+        let current = BTreeMap::from([(1, "6440".to_string())]);
+        assert_eq!(
+            diff(7, &previous, &current),
+            [
+                "Day 07 part 1: 6440 (unchanged)",
+                "Day 07 part 2: no answer (was 5905)"
+            ]
+        );
+        let current = BTreeMap::from([(1, "6592".to_string()), (2, "5905".to_string())]);
+        assert_eq!(
+            diff(7, &BTreeMap::new(), &current),
+            ["Day 07 part 1: 6592 (new)", "Day 07 part 2: 5905 (new)"]
+        );
+        assert_eq!(
+            diff(7, &previous, &current),
+            [
+                "Day 07 part 1: 6592 (was 6440)",
+                "Day 07 part 2: 5905 (unchanged)"
+            ]
+        );
+    }
+}
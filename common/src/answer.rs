@@ -0,0 +1,59 @@
+use std::fmt;
+
+/// Answer of a puzzle part, shared by every day so tooling can handle them uniformly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The part has no puzzle (e.g. day 25 part 2).
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+impl_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests_answer {
+    use super::*;
+
+    #[test]
+    fn test_from_number() {
+        assert_eq!(Answer::from(42u16), Answer::Number(42));
+        assert_eq!(Answer::from(-2i64), Answer::Number(-2));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        let answer: Answer = 5.into();
+        assert_eq!(answer, Answer::from(5usize));
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::{Answer, Part};
+
+const HEADER: &str = "# Expected answers of each day, maintained with `aoc verify --record`.\n";
+
+/// Expected answers by day and part, stored as a small subset of TOML read by both the runner
+/// and the mock server:
+///
+/// ```toml
+/// [day-01]
+/// part_1 = 142
+/// part_2 = "text answer"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), Answer>,
+}
+
+impl Answers {
+    /// Reads the manifest at `path`, a missing file is an empty manifest.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}:{err}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("could not read `{}`: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_toml())
+            .map_err(|err| format!("could not write `{}`: {err}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        let mut day = None;
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: &str| format!("{}: {message}", index + 1);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                day = Some(
+                    section
+                        .strip_prefix("day-")
+                        .and_then(|d| d.parse::<u8>().ok())
+                        .filter(|d| (1..=25).contains(d))
+                        .ok_or_else(|| error(&format!("invalid section `[{section}]`")))?,
+                );
+                continue;
+            }
+            let day = day.ok_or_else(|| error("expected a `[day-XX]` section"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `part_1 = <answer>`"))?;
+            let part = match key.trim() {
+                "part_1" => Part::One,
+                "part_2" => Part::Two,
+                key => return Err(error(&format!("unknown key `{key}`"))),
+            };
+            let answer = parse_answer(value.trim())
+                .ok_or_else(|| error(&format!("invalid answer `{}`", value.trim())))?;
+            answers.insert(day, part, answer);
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part.number()))
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: Answer) {
+        self.answers.insert((day, part.number()), answer);
+    }
+
+    /// Answers with their day and part number, sorted by day then part.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, u8, &Answer)> + '_ {
+        self.answers
+            .iter()
+            .map(|(&(day, part), answer)| (day, part, answer))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::from(HEADER);
+        let mut current_day = None;
+        for ((day, part), answer) in &self.answers {
+            if current_day != Some(*day) {
+                toml += &format!("\n[day-{day:02}]\n");
+                current_day = Some(*day);
+            }
+            let value = match answer {
+                Answer::Number(n) => n.to_string(),
+                Answer::Text(s) => format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+                Answer::None => continue,
+            };
+            toml += &format!("part_{part} = {value}\n");
+        }
+        toml
+    }
+}
+
+/// Integer or basic string value of the answers manifest.
+pub fn parse_answer(value: &str) -> Option<Answer> {
+    if let Some(text) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        let mut answer = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => answer.push(chars.next().filter(|c| matches!(c, '\\' | '"'))?),
+                '"' => return None,
+                c => answer.push(c),
+            }
+        }
+        return Some(Answer::Text(answer));
+    }
+    value.parse().ok().map(Answer::Number)
+}
+
+#[cfg(test)]
+mod tests_answers {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(12, Part::Two, 525152.into());
+        answers.insert(1, Part::One, (-142).into());
+        answers.insert(1, Part::Two, "say \"hi\" \\o/".into());
+        let toml = answers.to_toml();
+        assert_eq!(
+            toml,
+            format!("{HEADER}\n[day-01]\npart_1 = -142\npart_2 = \"say \\\"hi\\\" \\\\o/\"\n\n[day-12]\npart_2 = 525152\n")
+        );
+        assert_eq!(
+            answers
+                .iter()
+                .map(|(day, part, _)| (day, part))
+                .collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (12, 2)]
+        );
+        assert_eq!(Answers::parse(&toml), Ok(answers));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Answers::parse("part_1 = 3"),
+            Err("1: expected a `[day-XX]` section".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day-02]\n\npart_3 = 3"),
+            Err("3: unknown key `part_3`".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day-26]"),
+            Err("1: invalid section `[day-26]`".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day-02]\npart_1 = \"oops"),
+            Err("2: invalid answer `\"oops`".to_string())
+        );
+    }
+}
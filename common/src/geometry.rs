@@ -0,0 +1,192 @@
+/// Cardinal direction on a grid where `y` grows toward the south.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// `(dx, dy)` of a single step in this direction.
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+macro_rules! impl_points {
+    ($($t:ty),*) => {$(
+        impl Point2<$t> {
+            /// Point one step away in `direction`, `None` if it does not fit in the coordinates type.
+            pub fn step(self, direction: Direction) -> Option<Self> {
+                self.step_n(direction, 1)
+            }
+
+            /// Point `n` steps away in `direction`, `None` if it does not fit in the coordinates type.
+            pub fn step_n(self, direction: Direction, n: $t) -> Option<Self> {
+                Some(match direction {
+                    Direction::North => Self::new(self.x, self.y.checked_sub(n)?),
+                    Direction::East => Self::new(self.x.checked_add(n)?, self.y),
+                    Direction::South => Self::new(self.x, self.y.checked_add(n)?),
+                    Direction::West => Self::new(self.x.checked_sub(n)?, self.y),
+                })
+            }
+
+            /// Points one step away in each direction, in the order of [`Direction::ALL`].
+            pub fn neighbours(self) -> impl Iterator<Item = Self> {
+                Direction::ALL.into_iter().filter_map(move |direction| self.step(direction))
+            }
+
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                Some(Self::new(self.x.checked_add(other.x)?, self.y.checked_add(other.y)?))
+            }
+
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(Self::new(self.x.checked_sub(other.x)?, self.y.checked_sub(other.y)?))
+            }
+
+            pub fn manhattan_distance(self, other: Self) -> u64 {
+                self.x.abs_diff(other.x) as u64 + self.y.abs_diff(other.y) as u64
+            }
+        }
+
+        impl Point3<$t> {
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                Some(Self::new(
+                    self.x.checked_add(other.x)?,
+                    self.y.checked_add(other.y)?,
+                    self.z.checked_add(other.z)?,
+                ))
+            }
+
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(Self::new(
+                    self.x.checked_sub(other.x)?,
+                    self.y.checked_sub(other.y)?,
+                    self.z.checked_sub(other.z)?,
+                ))
+            }
+
+            pub fn manhattan_distance(self, other: Self) -> u64 {
+                self.x.abs_diff(other.x) as u64
+                    + self.y.abs_diff(other.y) as u64
+                    + self.z.abs_diff(other.z) as u64
+            }
+        }
+    )*};
+}
+
+impl_points!(u16, u32, u64, usize, i32, i64);
+
+#[cfg(test)]
+mod tests_geometry {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            let (dx, dy) = direction.delta();
+            assert_eq!(direction.reverse().delta(), (-dx, -dy));
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert!(Direction::South.is_vertical());
+    }
+
+    #[test]
+    fn test_point2() {
+        let origin = Point2::<usize>::new(0, 0);
+        assert_eq!(origin.step(Direction::North), None);
+        assert_eq!(origin.step(Direction::South), Some(Point2::new(0, 1)));
+        assert_eq!(
+            origin.neighbours().collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1)]
+        );
+
+        let point = Point2::<i64>::new(-2, 3);
+        assert_eq!(point.step_n(Direction::West, 4), Some(Point2::new(-6, 3)));
+        assert_eq!(point.manhattan_distance(Point2::new(1, -1)), 7);
+        assert_eq!(Point2::new(u16::MAX, 0).step(Direction::East), None);
+    }
+
+    #[test]
+    fn test_point3() {
+        let point = Point3::<u32>::new(1, 2, 3);
+        assert_eq!(
+            point.checked_add(Point3::new(1, 1, 1)),
+            Some(Point3::new(2, 3, 4))
+        );
+        assert_eq!(point.checked_sub(Point3::new(0, 0, 4)), None);
+        assert_eq!(point.manhattan_distance(Point3::new(3, 0, 3)), 4);
+    }
+}
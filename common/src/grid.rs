@@ -0,0 +1,328 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{ParseError, Point2};
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangular grid stored row by row, cells are indexed by `(x, y)` with `(0, 0)` the top-left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character, `cell` returns `None` for the characters that are not allowed.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let start = cells.len();
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::at(input, &line[i..], format!("unexpected character `{c}`"))
+                })?;
+                cells.push(value);
+            }
+            let row_width = cells.len() - start;
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("expected {width} cells, found {row_width}"),
+                    ));
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                cells,
+                width,
+                height,
+            }),
+            _ => Err(ParseError::new(
+                1,
+                1,
+                "expected a grid, found an empty input",
+            )),
+        }
+    }
+
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows must all have the same length"
+        );
+        let height = rows.len();
+        Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Cell at `(x, y)` on the grid repeated infinitely in every direction.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        // `step_by` panics on a zero step, a zero-width grid has no cells to walk anyway.
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells with their coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    /// Coordinates of the cells above, right, below and left of `(x, y)` that are in the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Same as [`Grid::neighbours4`], including the diagonals.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    fn neighbours(
+        &self,
+        x: usize,
+        y: usize,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            (x < width && y < height).then_some((x, y))
+        })
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Text with one line per row, `cell` gives the character of each cell.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&mut cell));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Swaps the rows and the columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "({x}, {y}) is outside of the grid");
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "({x}, {y}) is outside of the grid");
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests_grid {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let digit = |c: char| c.to_digit(10);
+        let grid = Grid::parse("123\n456\n", digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get(1, 1), Some(&5));
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(
+            Grid::parse("12\n3x", digit).unwrap_err().to_string(),
+            "line 2, column 2: unexpected character `x`"
+        );
+        assert_eq!(
+            Grid::parse("12\n345", digit).unwrap_err().to_string(),
+            "line 2, column 1: expected 2 cells, found 3"
+        );
+        assert!(Grid::parse("", digit).is_err());
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)));
+        assert_eq!(*grid.get_wrapping(-1, 3), 6);
+        assert_eq!(*grid.get_wrapping(4, -2), 2);
+    }
+
+    #[test]
+    fn test_zero_width() {
+        let grid: Grid<u8> = Grid::from_rows(vec![vec![], vec![]]);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[] as &[u8], &[]]);
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(Grid::new(0, 3, 'x').render(|c| *c), "\n\n\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(
+            grid.neighbours8(2, 2).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (1, 2)]
+        );
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::parse("ab\ncd\nef", Some).unwrap();
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "bdf\nace\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).render(|c| *c),
+            "AB\nCD\nEF\n"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::io;
+use std::path::Path;
+
+/// Reads an input file and normalizes it with [`normalize_input`].
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    Ok(normalize_input(&std::fs::read_to_string(path)?))
+}
+
+/// Strips the byte order mark, converts CRLF line endings to LF and removes the trailing
+/// whitespace of every line, so an input gives the same answers whatever system it comes from.
+///
+/// Trailing blank lines are dropped and a non empty input ends with a single `\n`.
+pub fn normalize_input(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = String::with_capacity(input.len());
+    for line in input.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    let len = normalized.trim_end_matches('\n').len();
+    normalized.truncate(if len == 0 { 0 } else { len + 1 });
+    normalized
+}
+
+/// Splits the input on blank lines, the blocks are slices of `input`.
+pub fn split_blocks(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut block: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((start, end)) = block.take() {
+                blocks.push(&input[start..end]);
+            }
+        } else {
+            let start = block.map_or(offset, |(start, _)| start);
+            block = Some((start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end)) = block {
+        blocks.push(&input[start..end]);
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests_input {
+    use super::*;
+    use crate::ParseError;
+
+    #[test]
+    fn test_normalize_input() {
+        let lf = "#.#\n.#.\n\n##\n";
+        assert_eq!(normalize_input(lf), lf);
+        assert_eq!(
+            normalize_input("\u{feff}#.# \r\n.#.\t\r\n\r\n##\r\n\r\n"),
+            lf
+        );
+        assert_eq!(normalize_input("##"), "##\n");
+        assert_eq!(normalize_input("\n \r\n"), "");
+    }
+
+    #[test]
+    fn test_split_blocks() {
+        let input = "ab\r\ncd\r\n\r\nef\n\n\ngh\n";
+        assert_eq!(split_blocks(input), vec!["ab\r\ncd", "ef", "gh"]);
+        let error = ParseError::new(2, 1, "bad").within(input, split_blocks(input)[1]);
+        assert_eq!((error.line(), error.column()), (5, 1));
+    }
+}
@@ -0,0 +1,19 @@
+mod answer;
+mod answers;
+mod geometry;
+mod grid;
+mod input;
+mod parse;
+mod property;
+mod rng;
+mod solution;
+
+pub use answer::Answer;
+pub use answers::{parse_answer, Answers};
+pub use geometry::{Direction, Point2, Point3};
+pub use grid::Grid;
+pub use input::{normalize_input, read_input, split_blocks};
+pub use parse::{parse_number, ParseError};
+pub use property::check_property;
+pub use rng::Rng;
+pub use solution::{DynSolution, Part, Solution};
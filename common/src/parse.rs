@@ -0,0 +1,130 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned by the parsers when the input does not have the expected format.
+///
+/// Lines and columns start at 1, columns are counted in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Error located at the start of `at`, which must be a slice of `input`.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let (line, column) = position(input, at);
+        Self::new(line, column, message)
+    }
+
+    /// Moves an error found while parsing `part`, a slice of `input`, to its position in `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let (line, column) = position(input, part);
+        if self.line == 1 {
+            Self::new(line, column + self.column - 1, self.message)
+        } else {
+            Self::new(line + self.line - 1, self.column, self.message)
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Multi-line diagnostic quoting the faulty line of `input` with a caret under the column.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let mut diagnostic = self.to_string();
+        if let Some(source) = input.lines().nth(self.line - 1) {
+            let number = self.line.to_string();
+            let margin = " ".repeat(number.len());
+            diagnostic += &format!(
+                "\n{margin} |\n{number} | {source}\n{margin} | {}^",
+                " ".repeat(self.column - 1)
+            );
+        }
+        diagnostic
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `at`, a slice of `input`, as a number.
+pub fn parse_number<T: FromStr>(input: &str, at: &str) -> Result<T, ParseError> {
+    at.parse()
+        .map_err(|_| ParseError::at(input, at, format!("expected a number, found `{at}`")))
+}
+
+/// Line and column of the start of `at` in `input`, the start of `input` if `at` is not part of it.
+fn position(input: &str, at: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let offset = (at.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|offset| *offset <= input.len())
+        .unwrap_or(0);
+
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests_parse {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let input = "ab\ncdé fg\n";
+        let at = &input[8..];
+        assert_eq!(
+            ParseError::at(input, at, "").to_string(),
+            "line 2, column 5: "
+        );
+
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::new(1, 2, "bad").within(input, line);
+        assert_eq!((error.line(), error.column()), (2, 2));
+        assert_eq!(
+            error.diagnostic(input),
+            "line 2, column 2: bad\n  |\n2 | cdé fg\n  |  ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_number() {
+        let input = "1 x2";
+        assert_eq!(parse_number::<u32>(input, &input[..1]), Ok(1));
+        let error = parse_number::<u32>(input, &input[2..]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected a number, found `x2`"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::Rng;
+
+/// Checks `property` with `cases` generators seeded from 0, or only with the seed set in the
+/// `AOC_PROPERTY_SEED` environment variable. Panics with the seed of the first failing case so
+/// it can be replayed.
+pub fn check_property(cases: u64, property: impl Fn(&mut Rng)) {
+    let seeds = match std::env::var("AOC_PROPERTY_SEED") {
+        Ok(seed) => {
+            let seed: u64 = seed.parse().expect("AOC_PROPERTY_SEED must be a number");
+            seed..seed + 1
+        }
+        Err(_) => 0..cases,
+    };
+    for seed in seeds {
+        if catch_unwind(AssertUnwindSafe(|| property(&mut Rng::new(seed)))).is_err() {
+            panic!("property failed with seed {seed}, replay it with AOC_PROPERTY_SEED={seed}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_property {
+    use super::*;
+
+    #[test]
+    fn test_check_property() {
+        check_property(100, |rng| assert!(rng.below(10) < 10));
+
+        let error = catch_unwind(|| {
+            check_property(100, |rng| assert_ne!(rng.below(20), 3));
+        })
+        .unwrap_err();
+        let message = error.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("property failed with seed "));
+        let seed: u64 = message.rsplit('=').next().unwrap().parse().unwrap();
+        let mut rng = Rng::new(seed);
+        assert_eq!(rng.below(20), 3);
+    }
+}
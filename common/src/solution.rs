@@ -0,0 +1,64 @@
+use crate::{normalize_input, Answer, ParseError};
+use std::any::Any;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Common interface of every day: the input is parsed once into a model, then both parts are
+/// solved from that model.
+pub trait Solution {
+    /// Parsed model shared by both parts.
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(&self, input: &Self::Input) -> Answer;
+    fn part_2(&self, input: &Self::Input) -> Answer;
+
+    /// Normalizes and parses the input then solves the requested part.
+    fn solve(&self, input: &str, part: Part) -> Result<Answer, ParseError> {
+        let input = self.parse(&normalize_input(input))?;
+        Ok(match part {
+            Part::One => self.part_1(&input),
+            Part::Two => self.part_2(&input),
+        })
+    }
+}
+
+/// Object safe version of [`Solution`] so days can be stored together and driven by tools.
+pub trait DynSolution {
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    /// Solves a part from a model returned by [`DynSolution::parse_dyn`] of the same solution.
+    fn part_dyn(&self, input: &dyn Any, part: Part) -> Answer;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn part_dyn(&self, input: &dyn Any, part: Part) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by another solution");
+        match part {
+            Part::One => self.part_1(input),
+            Part::Two => self.part_2(input),
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use std::sync::OnceLock;
+
+use common::{Answer, ParseError, Part, Solution};
+pub use vocabulary::Vocabulary;
+
+pub mod search_tree;
+mod vocabulary;
+
+#[derive(Default)]
+pub struct Day01 {
+    /// Tokens of the digits in part 2.
+    pub vocabulary: Vocabulary,
+}
+
+impl Solution for Day01 {
+    /// Calibration values of each line in part 1 and part 2, `None` when the part reads no digit
+    /// in the line.
+    type Input = Vec<(Option<u32>, Option<u32>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(
+                |line| match (calib_value(line).ok(), self.vocabulary.calibration(line)) {
+                    (None, None) => Err(no_digit(line).within(input, line)),
+                    values => Ok(values),
+                },
+            )
+            .collect()
+    }
+
+    /// No answer when a line has no figure, as in the example of part 2, see [`Day01::total`].
+    fn part_1(&self, values: &Self::Input) -> Answer {
+        Self::total(values, Part::One).map_or(Answer::None, Answer::from)
+    }
+
+    /// No answer when the vocabulary reads no digit in a line, see [`Day01::total`].
+    fn part_2(&self, values: &Self::Input) -> Answer {
+        Self::total(values, Part::Two).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Day01 {
+    /// Sum of the calibration values of a part, an error points at the first line in which the
+    /// part reads no digit.
+    pub fn total(values: &[(Option<u32>, Option<u32>)], part: Part) -> Result<u32, ParseError> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, &(figures, tokens))| {
+                let value = match part {
+                    Part::One => figures,
+                    Part::Two => tokens,
+                };
+                value.ok_or_else(|| ParseError::new(index + 1, 1, "expected a digit in the line"))
+            })
+            .sum()
+    }
+}
+
+/// Calibration value of a line: its first and last digits.
+pub fn calib_value(line: &str) -> Result<u32, ParseError> {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    let first = digits.next().ok_or_else(|| no_digit(line))?;
+    Ok(first * 10 + digits.next_back().unwrap_or(first))
+}
+
+/// Calibration value of a line where digits can also be spelled out (`one`, `two`, ...).
+pub fn calib_value_imprv(line: &str) -> Result<u32, ParseError> {
+    static ENGLISH: OnceLock<Vocabulary> = OnceLock::new();
+    ENGLISH
+        .get_or_init(Vocabulary::english)
+        .calibration(line)
+        .ok_or_else(|| no_digit(line))
+}
+
+fn no_digit(line: &str) -> ParseError {
+    ParseError::at(line, line, "expected a digit in the line")
+}
+
+#[cfg(test)]
+mod tests_day01 {
+    use super::*;
+
+    #[test]
+    fn test_day_01_part1() {
+        assert_eq!(calib_value("1abc2"), Ok(12));
+        assert_eq!(calib_value("pqr3stu8vwx"), Ok(38));
+        assert_eq!(calib_value("a1b2c3d4e5f"), Ok(15));
+        assert_eq!(calib_value("treb7uchet"), Ok(77));
+    }
+    #[test]
+    fn test_day_02_part2() {
+        assert_eq!(calib_value_imprv("two1nine"), Ok(29));
+        assert_eq!(calib_value_imprv("eightwothree"), Ok(83));
+        assert_eq!(calib_value_imprv("abcone2threexyz"), Ok(13));
+        assert_eq!(calib_value_imprv("xtwone3four"), Ok(24));
+        assert_eq!(calib_value_imprv("4nineeightseven2"), Ok(42));
+        assert_eq!(calib_value_imprv("zoneight234"), Ok(14));
+        assert_eq!(calib_value_imprv("7pqrstsixteen"), Ok(76));
+        assert_eq!(calib_value_imprv("twone"), Ok(21));
+    }
+
+    #[test]
+    fn test_no_digit() {
+        assert_eq!(
+            calib_value("abc").unwrap_err().to_string(),
+            "line 1, column 1: expected a digit in the line"
+        );
+        assert!(calib_value("").is_err());
+        assert!(calib_value_imprv("twelv").is_err());
+        assert_eq!(calib_value("05"), Ok(5));
+
+        let day = Day01::default();
+        let values = day.parse("1\ntwo\n").unwrap();
+        assert_eq!(
+            Day01::total(&values, Part::One),
+            Err(ParseError::new(2, 1, "expected a digit in the line"))
+        );
+        assert_eq!(day.part_1(&values), Answer::None);
+        assert_eq!(Day01::total(&values, Part::Two), Ok(33));
+        assert_eq!(day.part_2(&values), 33.into());
+        let values = Day01 {
+            vocabulary: Vocabulary::new([("two", 2)], false),
+        }
+        .parse("two\n1\n")
+        .unwrap();
+        assert_eq!(
+            Day01::total(&values, Part::Two),
+            Err(ParseError::new(2, 1, "expected a digit in the line"))
+        );
+        let input = "1\nabc\n";
+        let err = day.parse(input).unwrap_err();
+        assert_eq!(
+            err.diagnostic(input),
+            "line 2, column 1: expected a digit in the line\n  |\n2 | abc\n  | ^"
+        );
+    }
+
+    #[test]
+    fn test_multibyte() {
+        assert_eq!(calib_value("é1ü–2ß"), Ok(12));
+        assert_eq!(calib_value("日本語"), Err(no_digit("日本語")));
+        assert_eq!(calib_value("٣"), Err(no_digit("٣")));
+        assert_eq!(calib_value_imprv("ééone€€2ñ"), Ok(12));
+        assert_eq!(calib_value_imprv("9ßtwo日one"), Ok(91));
+        assert_eq!(calib_value_imprv("ëight7"), Ok(77));
+
+        // The Kelvin sign folds to a shorter `k`, positions stay in the folded line
+        let vocabulary = Vocabulary::new([("k", 5), ("été", 3), ("1", 1)], true);
+        assert_eq!(vocabulary.calibration("\u{212A}ÉTÉ1"), Some(51));
+        assert_eq!(vocabulary.calibration("1ÉTÉ\u{212A}été"), Some(13));
+    }
+
+    #[test]
+    fn test_search_tree() {
+        use search_tree::StringSearchTree;
+
+        let mut tree = StringSearchTree::new();
+        assert_eq!(tree.insert("one", 1), None);
+        assert_eq!(tree.insert("two", 2), None);
+        assert_eq!(tree.insert("three", 3), None);
+        assert_eq!(tree.insert("on", 4), None);
+        assert_eq!(tree.insert("one", 5), Some(1));
+        assert_eq!(tree.len(), 4);
+
+        assert!(!tree.contains_prefix("n"));
+        assert!(tree.contains_prefix("th"));
+        assert!(tree.contains_prefix(""));
+        assert_eq!(tree.get("one"), Some(&5));
+        assert_eq!(tree.get("o"), None);
+        assert_eq!(tree.longest_prefix("oneight"), Some(("one", &5)));
+        assert_eq!(tree.longest_prefix("ont"), Some(("on", &4)));
+        assert_eq!(tree.longest_prefix("n"), None);
+        *tree.get_mut("on").unwrap() += 10;
+        assert_eq!(
+            tree.iter_prefix("o").collect::<Vec<_>>(),
+            [("on".to_string(), &14), ("one".to_string(), &5)]
+        );
+        assert_eq!(
+            tree.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+            ["on", "one", "three", "two"]
+        );
+
+        assert_eq!(tree.remove("on"), Some(14));
+        assert_eq!(tree.remove("on"), None);
+        assert_eq!(tree.remove("onex"), None);
+        assert_eq!(tree.get("one"), Some(&5));
+        assert_eq!(tree.remove("three"), Some(3));
+        assert!(!tree.contains_prefix("th"));
+        assert!(tree.contains_prefix("tw"));
+        assert_eq!(tree.remove("one"), Some(5));
+        assert_eq!(tree.remove("two"), Some(2));
+        assert!(tree.is_empty());
+        assert!(!tree.contains_prefix(""));
+        assert_eq!(tree.iter().count(), 0);
+
+        let tree: StringSearchTree<char> = [("", 'a'), ("été", 'b')].into_iter().collect();
+        assert_eq!(tree.longest_prefix("ét"), Some(("", &'a')));
+        assert_eq!(tree.longest_prefix("étés"), Some(("été", &'b')));
+    }
+
+    #[test]
+    fn test_search_tree_matches() {
+        use common::check_property;
+        use search_tree::StringSearchTree;
+        use std::collections::BTreeMap;
+
+        check_property(300, |rng| {
+            let mut word = |length: usize| -> String {
+                (0..length).map(|_| ['a', 'b', 'é'][rng.below(3)]).collect()
+            };
+            let mut words = BTreeMap::new();
+            let mut tree = StringSearchTree::new();
+            for value in 1..=8 {
+                let key = word(value as usize % 4);
+                assert_eq!(tree.insert(&key, value), words.insert(key, value));
+            }
+            for _ in 0..3 {
+                let key = word(2);
+                assert_eq!(tree.remove(&key), words.remove(&key));
+            }
+            assert_eq!(tree.len(), words.len());
+            assert!(tree
+                .iter()
+                .map(|(key, value)| (key, *value))
+                .eq(words.clone()));
+
+            for _ in 0..20 {
+                let string = word(4);
+                let longest = words
+                    .iter()
+                    .filter(|(key, _)| string.starts_with(key.as_str()))
+                    .max_by_key(|(key, _)| key.len())
+                    .map(|(key, value)| (key.as_str(), value));
+                assert_eq!(
+                    tree.longest_prefix(&string),
+                    longest,
+                    "{string} in {words:?}"
+                );
+
+                let prefix = &string[..string.char_indices().nth(2).unwrap().0];
+                let under: Vec<(String, &u32)> = words
+                    .iter()
+                    .filter(|(key, _)| key.starts_with(prefix))
+                    .map(|(key, value)| (key.clone(), value))
+                    .collect();
+                assert_eq!(tree.contains_prefix(prefix), !under.is_empty());
+                assert_eq!(tree.iter_prefix(prefix).collect::<Vec<_>>(), under);
+                assert_eq!(tree.get(&string), words.get(&string));
+            }
+        });
+    }
+
+    #[test]
+    fn test_search_automaton() {
+        use search_tree::{SearchAutomaton, StringSearchTree};
+
+        let mut tree = StringSearchTree::new();
+        for (word, value) in [
+            ("one", 1),
+            ("two", 2),
+            ("three", 3),
+            ("eight", 8),
+            ("été", 9),
+        ] {
+            tree.insert(word, value);
+        }
+        let automaton = SearchAutomaton::new(tree);
+        let matches = |string| -> Vec<(u32, usize, usize)> {
+            automaton
+                .find_overlapping(string)
+                .map(|found| (*found.value, found.start, found.end))
+                .collect()
+        };
+        assert_eq!(matches("twone"), [(2, 0, 3), (1, 2, 5)]);
+        assert_eq!(matches("eightwothree"), [(8, 0, 5), (2, 4, 7), (3, 7, 12)]);
+        assert_eq!(matches("étété"), [(9, 0, 5), (9, 3, 8)]);
+        assert_eq!(matches("thre"), []);
+        assert_eq!(
+            SearchAutomaton::new(StringSearchTree::<u32>::new())
+                .find_overlapping("one")
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_search_automaton_matches() {
+        use common::check_property;
+        use search_tree::SearchAutomaton;
+
+        check_property(300, |rng| {
+            let mut word = |length: usize| -> String {
+                (0..length).map(|_| ['a', 'b', 'é'][rng.below(3)]).collect()
+            };
+            // Keys can be prefixes of one another, a repeated key keeps its last value
+            let mut words: Vec<(String, u32)> = Vec::new();
+            for value in 1..=6 {
+                let candidate = word(1 + value as usize % 4);
+                words.retain(|(w, _)| *w != candidate);
+                words.push((candidate, value));
+            }
+            let tree = words.iter().map(|(w, value)| (w, *value)).collect();
+            let automaton = SearchAutomaton::new(tree);
+
+            for _ in 0..20 {
+                let string = word(12);
+                let string = string.as_str();
+                let mut found: Vec<(usize, usize, u32)> = automaton
+                    .find_overlapping(string)
+                    .map(|found| (found.start, found.end, *found.value))
+                    .collect();
+                found.sort();
+                let mut expected: Vec<(usize, usize, u32)> = string
+                    .char_indices()
+                    .flat_map(|(start, _)| {
+                        words
+                            .iter()
+                            .filter(move |(w, _)| string[start..].starts_with(w.as_str()))
+                            .map(move |(w, value)| (start, start + w.len(), *value))
+                    })
+                    .collect();
+                expected.sort();
+                assert_eq!(found, expected, "{string} in {words:?}");
+            }
+        });
+    }
+}
@@ -1,196 +1,334 @@
-pub enum StringSearchTree {
-    Root {
-        neighboors: Vec<Box<StringSearchTree>>,
-    },
-    Leaf {
-        value: char,
-        neighboors: Vec<Box<StringSearchTree>>,
-    },
-    Empty {
-        return_value: u32,
-    },
+use std::collections::VecDeque;
+
+/// Dictionary of string keys stored as a prefix tree, its nodes live in an arena and refer to
+/// each other by index.
+#[derive(Debug, Clone)]
+pub struct StringSearchTree<V> {
+    nodes: Vec<Node<V>>,
+    /// Nodes freed by `remove`, reused by the next insertions.
+    free: Vec<usize>,
+    len: usize,
 }
-pub struct SearchMatchResult {
-    pub value: u32,
-    pub length: u32,
+
+#[derive(Debug, Clone)]
+struct Node<V> {
+    /// Children sorted by character.
+    children: Vec<(char, usize)>,
+    /// Value of the key ending at this node.
+    value: Option<V>,
 }
 
-impl Default for StringSearchTree {
+const ROOT: usize = 0;
+
+impl<V> Default for StringSearchTree<V> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl StringSearchTree {
-    pub fn new() -> StringSearchTree {
-        StringSearchTree::Root { neighboors: Vec::new() }
+impl<V> StringSearchTree<V> {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node {
+                children: Vec::new(),
+                value: None,
+            }],
+            free: Vec::new(),
+            len: 0,
+        }
     }
 
-    pub fn insert_leaf(&mut self, value: char) {
-        match self {
-            StringSearchTree::Root { neighboors } => {
-                if neighboors.len() == 1 && matches!(neighboors[0].as_ref(), StringSearchTree::Empty { .. }) {
-                    panic!("Cannot insert leaf at same level than empty node");
-                }
-                neighboors.push(Box::new(StringSearchTree::Leaf {
-                    value,
-                    neighboors: Vec::new(),
-                }));
-            }
-            StringSearchTree::Leaf { neighboors, .. } => {
-                if neighboors.len() == 1 && matches!(neighboors[0].as_ref(), StringSearchTree::Empty { .. }) {
-                    panic!("Cannot insert leaf at same level than empty node");
+    /// Number of keys.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts `key`, returns the value it replaced.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let mut node = ROOT;
+        for c in key.chars() {
+            node = match self.child(node, c) {
+                Some(child) => child,
+                None => {
+                    let child = self.allocate();
+                    let children = &mut self.nodes[node].children;
+                    let position = children.partition_point(|(other, _)| *other < c);
+                    children.insert(position, (c, child));
+                    child
                 }
-                neighboors.push(Box::new(StringSearchTree::Leaf {
-                    value,
-                    neighboors: Vec::new(),
-                }));
-            }
-            StringSearchTree::Empty { .. } => {
-                panic!("Cannot insert leaf into empty");
-            }
+            };
+        }
+        let previous = self.nodes[node].value.replace(value);
+        if previous.is_none() {
+            self.len += 1;
         }
+        previous
     }
 
-    pub fn insert_empty(&mut self, return_value: u32) {
-        match self {
-            StringSearchTree::Root { neighboors } => {
-                if !neighboors.is_empty() {
-                    panic!("Cannot insert empty into root with neighboors");
-                }
-                neighboors.push(Box::new(StringSearchTree::Empty {
-                    return_value,
-                }));
-            }
-            StringSearchTree::Leaf { neighboors, .. } => {
-                if !neighboors.is_empty() {
-                    panic!("Cannot insert empty into leaf with neighboors");
-                }
-                neighboors.push(Box::new(StringSearchTree::Empty {
-                    return_value,
-                }));
-            }
-            StringSearchTree::Empty { .. } => {
-                panic!("Cannot insert empty into empty");
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.value(self.find(key)?)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        let node = self.find(key)?;
+        self.nodes[node].value.as_mut()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes `key`, returns its value. The nodes no longer leading to a key are freed.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let mut path = vec![ROOT];
+        for c in key.chars() {
+            path.push(self.child(*path.last()?, c)?);
+        }
+        let value = self.nodes[*path.last()?].value.take()?;
+        self.len -= 1;
+
+        for (parent, node) in path.iter().zip(path.iter().skip(1)).rev() {
+            let pruned = &self.nodes[*node];
+            if pruned.value.is_some() || !pruned.children.is_empty() {
+                break;
             }
+            self.nodes[*parent]
+                .children
+                .retain(|(_, child)| child != node);
+            self.free.push(*node);
         }
+        Some(value)
     }
 
-    pub fn find_neighbour(&self, value: char) -> Option<&StringSearchTree> {
-        match self {
-            StringSearchTree::Root { neighboors } => {
-                for neighboor in neighboors.iter() {
-                    if let StringSearchTree::Leaf { value: v, .. } = neighboor.as_ref() {
-                        if *v == value {
-                            return Some(neighboor.as_ref());
-                        }
-                    }
-                }
-                None
-            }
-            StringSearchTree::Leaf { neighboors, .. } => {
-                for neighboor in neighboors.iter() {
-                    if let StringSearchTree::Leaf { value: v, .. } = neighboor.as_ref() {
-                        if *v == value {
-                            return Some(neighboor.as_ref());
-                        }
-                    }
-                }
-                None
+    /// Whether a key starts with `prefix`.
+    pub fn contains_prefix(&self, prefix: &str) -> bool {
+        // Removal prunes the nodes leading to no key, only the root can be empty
+        self.find(prefix)
+            .is_some_and(|node| node != ROOT || !self.is_empty())
+    }
+
+    /// Longest key that `string` starts with, and its value.
+    pub fn longest_prefix<'a>(&self, string: &'a str) -> Option<(&'a str, &V)> {
+        let mut node = ROOT;
+        let mut longest = self.value(ROOT).map(|value| (0, value));
+        for (index, c) in string.char_indices() {
+            let Some(child) = self.child(node, c) else {
+                break;
+            };
+            node = child;
+            if let Some(value) = self.value(node) {
+                longest = Some((index + c.len_utf8(), value));
             }
-            StringSearchTree::Empty { .. } => None,
         }
+        longest.map(|(end, value)| (&string[..end], value))
     }
-    
-    pub fn find_neighbour_mut(&mut self, value: char) -> Option<&mut StringSearchTree> {
-        match self {
-            StringSearchTree::Root { neighboors } => {
-                for neighboor in neighboors.iter_mut() {
-                    if let StringSearchTree::Leaf { value: v, .. } = neighboor.as_ref() {
-                        if *v == value {
-                            return Some(neighboor.as_mut());
-                        }
-                    }
-                }
-                None
+
+    /// Keys starting with `prefix` and their values, in the order of their characters.
+    pub fn iter_prefix(&self, prefix: &str) -> PrefixIter<'_, V> {
+        PrefixIter {
+            tree: self,
+            stack: self
+                .find(prefix)
+                .map(|node| (node, prefix.to_string()))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    pub fn iter(&self) -> PrefixIter<'_, V> {
+        self.iter_prefix("")
+    }
+
+    fn find(&self, key: &str) -> Option<usize> {
+        key.chars().try_fold(ROOT, |node, c| self.child(node, c))
+    }
+
+    fn child(&self, node: usize, c: char) -> Option<usize> {
+        let children = &self.nodes[node].children;
+        children
+            .binary_search_by_key(&c, |(other, _)| *other)
+            .ok()
+            .map(|index| children[index].1)
+    }
+
+    fn value(&self, node: usize) -> Option<&V> {
+        self.nodes[node].value.as_ref()
+    }
+
+    fn allocate(&mut self) -> usize {
+        let node = Node {
+            children: Vec::new(),
+            value: None,
+        };
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
             }
-            StringSearchTree::Leaf { neighboors, .. } => {
-                for neighboor in neighboors.iter_mut() {
-                    if let StringSearchTree::Leaf { value: v, .. } = neighboor.as_ref() {
-                        if *v == value {
-                            return Some(neighboor.as_mut());
-                        }
-                    }
-                }
-                None
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
             }
-            StringSearchTree::Empty { .. } => None,
         }
     }
+}
 
-    pub fn find_empty(&self) -> Option<&StringSearchTree> {
-        match self {
-            StringSearchTree::Root { neighboors } => {
-                for neighboor in neighboors.iter() {
-                    if let StringSearchTree::Empty { .. } = neighboor.as_ref() {
-                        return Some(neighboor.as_ref());
-                    }
-                }
-                None
+impl<K: AsRef<str>, V> FromIterator<(K, V)> for StringSearchTree<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        let mut tree = Self::new();
+        for (key, value) in entries {
+            tree.insert(key.as_ref(), value);
+        }
+        tree
+    }
+}
+
+/// Iterator over the keys of a tree under a prefix, see [`StringSearchTree::iter_prefix`].
+pub struct PrefixIter<'a, V> {
+    tree: &'a StringSearchTree<V>,
+    /// Nodes left to visit with their key, the next one last.
+    stack: Vec<(usize, String)>,
+}
+
+impl<'a, V> Iterator for PrefixIter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, key)) = self.stack.pop() {
+            for (c, child) in self.tree.nodes[node].children.iter().rev() {
+                self.stack.push((*child, format!("{key}{c}")));
             }
-            StringSearchTree::Leaf { neighboors, .. } => {
-                for neighboor in neighboors.iter() {
-                    if let StringSearchTree::Empty { .. } = neighboor.as_ref() {
-                        return Some(neighboor.as_ref());
-                    }
-                }
-                None
+            if let Some(value) = self.tree.value(node) {
+                return Some((key, value));
             }
-            StringSearchTree::Empty { .. } => None,
         }
+        None
     }
+}
 
-    pub fn insert_string_on_root(&mut self, string: &str, value: u32) {
-        if !matches!(self, StringSearchTree::Root { .. }) {
-            panic!("Cannot insert string on non-root node");
-        }
+/// Occurrence of a key of the tree in a scanned string, `start..end` are byte offsets.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SearchMatch<'a, V> {
+    pub value: &'a V,
+    pub start: usize,
+    pub end: usize,
+}
 
-        let mut current_node = self;
-        for char in string.chars() {
-            let matching_neighboor = current_node.find_neighbour(char);
-            if matching_neighboor.is_none() {
-                current_node.insert_leaf(char);
-            } 
-            current_node = current_node.find_neighbour_mut(char).unwrap();
+impl<V> Clone for SearchMatch<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for SearchMatch<'_, V> {}
+
+/// Aho–Corasick automaton: a [`StringSearchTree`] with failure links, so that a string is
+/// scanned once for every key at every position.
+#[derive(Debug, Clone)]
+pub struct SearchAutomaton<V> {
+    tree: StringSearchTree<V>,
+    /// For each node, the node of the longest proper suffix of its key that is in the tree.
+    fail: Vec<usize>,
+    /// For each node, the closest node on its failure chain that ends a key.
+    output_link: Vec<Option<usize>>,
+    /// For each node, the length in bytes of its key.
+    depth: Vec<usize>,
+}
+
+impl<V> SearchAutomaton<V> {
+    pub fn new(tree: StringSearchTree<V>) -> Self {
+        let size = tree.nodes.len();
+        let mut automaton = Self {
+            tree,
+            fail: vec![ROOT; size],
+            output_link: vec![None; size],
+            depth: vec![0; size],
+        };
+
+        // Breadth first, the failure link of a node is always closer to the root
+        let mut queue = VecDeque::from([ROOT]);
+        while let Some(node) = queue.pop_front() {
+            for index in 0..automaton.tree.nodes[node].children.len() {
+                let (c, child) = automaton.tree.nodes[node].children[index];
+                let fail = match node {
+                    ROOT => ROOT,
+                    _ => automaton.next(automaton.fail[node], c),
+                };
+                automaton.fail[child] = fail;
+                automaton.output_link[child] = match automaton.tree.value(fail) {
+                    Some(_) => Some(fail),
+                    None => automaton.output_link[fail],
+                };
+                automaton.depth[child] = automaton.depth[node] + c.len_utf8();
+                queue.push_back(child);
+            }
         }
-        current_node.insert_empty(value);
+        automaton
     }
 
+    pub fn tree(&self) -> &StringSearchTree<V> {
+        &self.tree
+    }
 
-    pub fn match_string_from_root(&self, string: &str) -> SearchMatchResult {
-        if matches!(self, StringSearchTree::Empty { .. }) {
-            panic!("Cannot match string on empty node");
+    /// State reached from `state` by reading `c`.
+    fn next(&self, mut state: usize, c: char) -> usize {
+        loop {
+            if let Some(child) = self.tree.child(state, c) {
+                return child;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.fail[state];
         }
-        if matches!(self, StringSearchTree::Leaf { .. }) {
-            panic!("Cannot match string on leaf node with this method!");
+    }
+
+    /// Every occurrence of the non-empty keys in `string`, overlapping ones included, ordered
+    /// by end then from the longest to the shortest.
+    pub fn find_overlapping<'a>(&'a self, string: &'a str) -> SearchMatches<'a, V> {
+        SearchMatches {
+            automaton: self,
+            chars: string.char_indices(),
+            state: ROOT,
+            output: None,
+            end: 0,
         }
-        let mut result = SearchMatchResult { value: 0, length: 0 };
-        let mut current_node = self;
-        for char in string.chars() {
-            let matching_neighboor = current_node.find_neighbour(char);
-            if matching_neighboor.is_none() {
-                break;
-            }
-            current_node = matching_neighboor.unwrap();
-            result.length += 1;
+    }
+}
+
+/// Iterator over the matches of an automaton, see [`SearchAutomaton::find_overlapping`].
+pub struct SearchMatches<'a, V> {
+    automaton: &'a SearchAutomaton<V>,
+    chars: std::str::CharIndices<'a>,
+    state: usize,
+    /// Next node of the output chain of the current state to report.
+    output: Option<usize>,
+    end: usize,
+}
+
+impl<'a, V> Iterator for SearchMatches<'a, V> {
+    type Item = SearchMatch<'a, V>;
 
-            let return_node = current_node.find_empty();
-            if return_node.is_some() {
-                if let StringSearchTree::Empty { return_value } = return_node.unwrap() {
-                    result.value = *return_value;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while let Some(node) = self.output {
+                self.output = self.automaton.output_link[node];
+                if let (Some(value), false) = (self.automaton.tree.value(node), node == ROOT) {
+                    return Some(SearchMatch {
+                        value,
+                        start: self.end - self.automaton.depth[node],
+                        end: self.end,
+                    });
                 }
             }
+            let (index, c) = self.chars.next()?;
+            self.state = self.automaton.next(self.state, c);
+            self.end = index + c.len_utf8();
+            self.output = Some(self.state);
         }
-        result
     }
 }
@@ -0,0 +1,244 @@
+use std::path::Path;
+
+use common::{parse_number, ParseError};
+
+use crate::search_tree::{SearchAutomaton, SearchMatch, StringSearchTree};
+
+/// Tokens standing for numbers in a calibration line, such as `one` or `7`.
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    automaton: SearchAutomaton<u32>,
+    ignore_case: bool,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Vocabulary {
+    /// Vocabulary of the given tokens, a repeated token keeps its last value.
+    pub fn new<K: AsRef<str>>(
+        tokens: impl IntoIterator<Item = (K, u32)>,
+        ignore_case: bool,
+    ) -> Self {
+        let tree: StringSearchTree<u32> = tokens
+            .into_iter()
+            .map(|(token, value)| match ignore_case {
+                true => (fold_case(token.as_ref()), value),
+                false => (token.as_ref().to_string(), value),
+            })
+            .collect();
+        Self {
+            automaton: SearchAutomaton::new(tree),
+            ignore_case,
+        }
+    }
+
+    /// The digits `0` to `9` written with a figure, and `1` to `9` spelled out in lowercase
+    /// English.
+    pub fn english() -> Self {
+        let numbers = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let figures = (0..=9).map(|value: u32| (value.to_string(), value));
+        let words = numbers
+            .into_iter()
+            .zip(1..)
+            .map(|(word, value)| (word.to_string(), value));
+        Self::new(figures.chain(words), false)
+    }
+
+    /// Vocabulary of a file with a `<token> <value>` pair per line, `#` starts a comment and an
+    /// `ignore-case` line makes the matching case-insensitive. A token can only be defined once,
+    /// ignoring its case when the matching does.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut entries = Vec::new();
+        let mut ignore_case = false;
+        for line in text.lines() {
+            let content = line.split('#').next().unwrap_or_default();
+            match content.split_whitespace().collect::<Vec<_>>()[..] {
+                [] => {}
+                ["ignore-case"] => ignore_case = true,
+                [token, value] => entries.push((token, parse_number(text, value)?)),
+                [first, ..] => {
+                    return Err(ParseError::at(
+                        text,
+                        first,
+                        "expected `<token> <value>` or `ignore-case`",
+                    ))
+                }
+            }
+        }
+
+        // `ignore-case` applies to the whole file, even to the tokens defined before it
+        let mut tokens = StringSearchTree::new();
+        for (token, value) in entries {
+            let key = match ignore_case {
+                true => fold_case(token),
+                false => token.to_string(),
+            };
+            if tokens.insert(&key, value).is_some() {
+                return Err(ParseError::at(
+                    text,
+                    token,
+                    format!("token `{token}` is defined twice"),
+                ));
+            }
+        }
+        Ok(Self::new(
+            tokens.iter().map(|(token, value)| (token, *value)),
+            ignore_case,
+        ))
+    }
+
+    /// Vocabulary of the file at `path`, see [`Vocabulary::parse`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("could not read `{}`: {err}", path.display()))?;
+        Self::parse(&text).map_err(|err| format!("{}: {}", path.display(), err.diagnostic(&text)))
+    }
+
+    /// Calibration value of a line: the first digit of its first token and the last digit of
+    /// its last one, `None` when the line has no token. At the same position the longest token
+    /// is used.
+    pub fn calibration(&self, line: &str) -> Option<u32> {
+        // Matches are compared within the folded line, which keeps the order of the characters
+        let folded;
+        let line = match self.ignore_case {
+            true => {
+                folded = fold_case(line);
+                &folded
+            }
+            false => line,
+        };
+
+        let mut first: Option<SearchMatch<u32>> = None;
+        let mut last: Option<SearchMatch<u32>> = None;
+        for found in self.automaton.find_overlapping(line) {
+            let longer = |other: &SearchMatch<u32>| found.end > other.end;
+            let before_first = match &first {
+                Some(first) => {
+                    found.start < first.start || found.start == first.start && longer(first)
+                }
+                None => true,
+            };
+            let after_last = match &last {
+                Some(last) => found.start > last.start || found.start == last.start && longer(last),
+                None => true,
+            };
+            if before_first {
+                first = Some(found);
+            }
+            if after_last {
+                last = Some(found);
+            }
+        }
+
+        let mut first = *first?.value;
+        while first >= 10 {
+            first /= 10;
+        }
+        Some(first * 10 + *last?.value % 10)
+    }
+}
+
+/// Lowercase `text` character by character, a character with no single lowercase one is kept.
+fn fold_case(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            let mut lower = c.to_lowercase();
+            match (lower.next(), lower.next()) {
+                (Some(lower), None) => lower,
+                _ => c,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_vocabulary {
+    use super::*;
+
+    #[test]
+    fn test_english() {
+        let english = Vocabulary::default();
+        assert_eq!(english.calibration("xtwone3four"), Some(24));
+        assert_eq!(english.calibration("eighthree"), Some(83));
+        assert_eq!(english.calibration("Two0"), Some(0));
+        assert_eq!(english.calibration("zero7"), Some(77));
+        assert_eq!(english.calibration("Two"), None);
+    }
+
+    #[test]
+    fn test_custom() {
+        let french = Vocabulary::new(
+            [
+                ("zéro", 0),
+                ("un", 1),
+                ("deux", 2),
+                ("dix", 10),
+                ("douze", 12),
+            ],
+            true,
+        );
+        assert_eq!(french.calibration("DIXdeux"), Some(12));
+        assert_eq!(french.calibration("aDouze"), Some(12));
+        assert_eq!(french.calibration("ZÉROun"), Some(1));
+        assert_eq!(french.calibration("dou"), None);
+
+        // At the same position the longest token wins
+        let vocabulary = Vocabulary::new([("seven", 7), ("seventeen", 17)], false);
+        assert_eq!(vocabulary.calibration("seventeen"), Some(17));
+        assert_eq!(vocabulary.calibration("Seventeen"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let text = "# English and German\nignore-case\none 1\neins 1  # German\n\nzwei 2\n";
+        let vocabulary = Vocabulary::parse(text).unwrap();
+        assert_eq!(vocabulary.calibration("EINSxtwozwei"), Some(12));
+        assert_eq!(vocabulary.calibration("one"), Some(11));
+
+        assert_eq!(
+            Vocabulary::parse("one 1\ntwo\n").unwrap_err().to_string(),
+            "line 2, column 1: expected `<token> <value>` or `ignore-case`"
+        );
+        assert_eq!(
+            Vocabulary::parse("one 1\none 2\n").unwrap_err().to_string(),
+            "line 2, column 1: token `one` is defined twice"
+        );
+        assert_eq!(
+            Vocabulary::parse("one 1\nOne 2\nignore-case\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: token `One` is defined twice"
+        );
+        assert_eq!(
+            Vocabulary::parse("one 1\nOne 2\n")
+                .unwrap()
+                .calibration("Oneone"),
+            Some(21)
+        );
+        assert_eq!(
+            Vocabulary::parse("one -1\n").unwrap_err().to_string(),
+            "line 1, column 5: expected a number, found `-1`"
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let path = std::env::temp_dir().join(format!("day-01-vocabulary-{}", std::process::id()));
+        std::fs::write(&path, "ten 10\n").unwrap();
+        assert_eq!(
+            Vocabulary::load(&path).unwrap().calibration("ten"),
+            Some(10)
+        );
+        std::fs::remove_file(&path).unwrap();
+        assert!(Vocabulary::load(&path)
+            .unwrap_err()
+            .starts_with("could not read"));
+    }
+}
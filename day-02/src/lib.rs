@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use common::{parse_number, Answer, ParseError, Solution};
+
+pub struct Day02 {
+    /// Cubes in the bag in part 1.
+    pub bag: CubeSet,
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        Self {
+            bag: CubeSet::from_iter([("red", 12), ("green", 13), ("blue", 14)]),
+        }
+    }
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| Game::parse(line).map_err(|err| err.within(input, line)))
+            .collect()
+    }
+
+    fn part_1(&self, games: &Self::Input) -> Answer {
+        games
+            .iter()
+            .filter(|game| game.is_feasible(&self.bag))
+            .map(|game| game.id)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_2(&self, games: &Self::Input) -> Answer {
+        // A color of the bag that a game never shows needs no cube
+        let colors: CubeSet = self.bag.colors().map(|color| (color, 0)).collect();
+        games
+            .iter()
+            .map(|game| game.minimal_bag().union(&colors).power())
+            .sum::<u64>()
+            .into()
+    }
+}
+
+/// Number of cubes of each color, such as a round of a game or the content of the bag.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CubeSet {
+    counts: BTreeMap<String, u32>,
+}
+
+impl CubeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of cubes of `color`, 0 for a color not in the set.
+    pub fn get(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    /// Sets the number of cubes of `color`, returns the previous one.
+    pub fn insert(&mut self, color: &str, count: u32) -> Option<u32> {
+        self.counts.insert(color.to_string(), count)
+    }
+
+    /// Colors of the set, in alphabetical order.
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(String::as_str)
+    }
+
+    /// Whether every cube of the set can be drawn from `bag` at once.
+    pub fn fits_in(&self, bag: &CubeSet) -> bool {
+        self.counts
+            .iter()
+            .all(|(color, count)| *count <= bag.get(color))
+    }
+
+    /// Smallest set that both sets fit in.
+    pub fn union(&self, other: &CubeSet) -> CubeSet {
+        let mut union = self.clone();
+        for (color, count) in &other.counts {
+            let union_count = union.counts.entry(color.clone()).or_insert(0);
+            *union_count = (*union_count).max(*count);
+        }
+        union
+    }
+
+    /// Product of the numbers of cubes of every color.
+    pub fn power(&self) -> u64 {
+        self.counts.values().map(|count| *count as u64).product()
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, u32)> for CubeSet {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(counts: I) -> Self {
+        let mut set = Self::new();
+        for (color, count) in counts {
+            set.insert(color.as_ref(), count);
+        }
+        set
+    }
+}
+
+impl FromStr for CubeSet {
+    type Err = ParseError;
+
+    /// Parses a `<count> <color>, ...` list, such as `3 blue, 4 red`.
+    fn from_str(text: &str) -> Result<Self, ParseError> {
+        let mut set = Self::new();
+        for color_slice in text.split(',') {
+            let color_slice = color_slice.trim();
+            let (count, color) = color_slice
+                .split_once(' ')
+                .filter(|(_, color)| !color.is_empty() && !color.contains(' '))
+                .ok_or_else(|| {
+                    ParseError::at(
+                        text,
+                        color_slice,
+                        format!("expected `<count> <color>`, found `{color_slice}`"),
+                    )
+                })?;
+            if set.insert(color, parse_number(text, count)?).is_some() {
+                return Err(ParseError::at(
+                    text,
+                    color,
+                    format!("color `{color}` is given twice"),
+                ));
+            }
+        }
+        Ok(set)
+    }
+}
+
+/// Game of a line, the cubes shown in each of its rounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<CubeSet>,
+}
+
+impl Game {
+    /// Parses a `Game <id>: <count> <color>, ...; ...` line.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (header, rounds) = line.split_once(':').ok_or_else(|| {
+            ParseError::at(line, &line[line.len()..], "expected `:` after the game id")
+        })?;
+        let id = match header.split_once(' ') {
+            Some(("Game", id)) => parse_number(line, id)?,
+            _ => return Err(ParseError::at(line, header, "expected `Game <id>`")),
+        };
+        let rounds = rounds
+            .split(';')
+            .map(|round| {
+                round
+                    .parse()
+                    .map_err(|err: ParseError| err.within(line, round))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { id, rounds })
+    }
+
+    /// Whether every round can be drawn from `bag`.
+    pub fn is_feasible(&self, bag: &CubeSet) -> bool {
+        self.rounds.iter().all(|round| round.fits_in(bag))
+    }
+
+    /// Fewest cubes of each color shown that make the game feasible.
+    pub fn minimal_bag(&self) -> CubeSet {
+        self.rounds
+            .iter()
+            .fold(CubeSet::new(), |bag, round| bag.union(round))
+    }
+
+    /// Power of the minimal bag of the game.
+    pub fn power(&self) -> u64 {
+        self.minimal_bag().power()
+    }
+}
+
+#[cfg(test)]
+mod tests_day02 {
+    use super::*;
+    use common::Part;
+
+    const GAMES: [&str; 5] = [
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+        "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+    ];
+
+    fn game(line: &str) -> Game {
+        Game::parse(line).unwrap()
+    }
+
+    #[test]
+    fn test_parse_game() {
+        assert_eq!(
+            game("Game 1: 3 blue, 4 red; 2 green"),
+            Game {
+                id: 1,
+                rounds: vec![
+                    CubeSet::from_iter([("blue", 3), ("red", 4)]),
+                    CubeSet::from_iter([("green", 2)]),
+                ],
+            }
+        );
+        assert_eq!(game("Game 98: 1 teal").id, 98);
+        assert_eq!(
+            Game::parse("Game 1: 3 blue, 4 light red")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 17: expected `<count> <color>`, found `4 light red`"
+        );
+        assert_eq!(
+            Game::parse("Game 1: 3 blue; 2 red, 1 red")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 26: color `red` is given twice"
+        );
+        let input = "Game 1: 1 red\nGame 2: x blue";
+        assert_eq!(
+            Day02::default().parse(input).unwrap_err().to_string(),
+            "line 2, column 9: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_is_feasible() {
+        let bag = Day02::default().bag;
+        let feasible: Vec<bool> = GAMES
+            .iter()
+            .map(|line| game(line).is_feasible(&bag))
+            .collect();
+        assert_eq!(feasible, [true, true, false, false, true]);
+
+        // A color missing from the bag has no cube
+        assert!(!game("Game 6: 1 teal").is_feasible(&bag));
+        let bag = CubeSet::from_iter([("teal", 1)]);
+        assert!(game("Game 6: 1 teal; 1 teal").is_feasible(&bag));
+    }
+
+    #[test]
+    fn test_minimal_bag() {
+        let minimal: Vec<CubeSet> = GAMES.iter().map(|line| game(line).minimal_bag()).collect();
+        let bag =
+            |red, green, blue| CubeSet::from_iter([("red", red), ("green", green), ("blue", blue)]);
+        assert_eq!(
+            minimal,
+            [
+                bag(4, 2, 6),
+                bag(1, 3, 4),
+                bag(20, 13, 6),
+                bag(14, 3, 15),
+                bag(6, 3, 2),
+            ]
+        );
+        let powers: Vec<u64> = GAMES.iter().map(|line| game(line).power()).collect();
+        assert_eq!(powers, [48, 12, 1560, 630, 36]);
+        assert_eq!(game("Game 7: 2 teal; 3 red, 1 teal").power(), 6);
+    }
+
+    #[test]
+    fn test_parts() {
+        let input = GAMES.join("\n");
+        assert_eq!(Day02::default().solve(&input, Part::One), Ok(8.into()));
+        assert_eq!(Day02::default().solve(&input, Part::Two), Ok(2286.into()));
+        assert_eq!(
+            Day02::default().solve("Game 1: 2 red, 3 green", Part::Two),
+            Ok(0.into())
+        );
+        let day = Day02 {
+            bag: CubeSet::from_iter([("teal", 3)]),
+        };
+        assert_eq!(
+            day.solve("Game 1: 2 teal\nGame 2: 4 teal", Part::One),
+            Ok(1.into())
+        );
+    }
+}
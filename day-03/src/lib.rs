@@ -0,0 +1,163 @@
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::HashMap;
+
+use common::{Answer, Grid, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, Some)
+    }
+
+    fn part_1(&self, schematic: &Self::Input) -> Answer {
+        sum_part_number(schematic).into()
+    }
+
+    fn part_2(&self, schematic: &Self::Input) -> Answer {
+        sum_gear_ratio(schematic).into()
+    }
+}
+
+/// Sum of the products of the two part numbers adjacent to each `*`.
+pub fn sum_gear_ratio(schematic: &Grid<char>) -> u32 {
+    let mut current_part = 0;
+    let mut keep_current_part = false;
+    let mut current_gear: [u32; 2] = [0, 0];
+    let mut gear_map: HashMap<(u32, u32), Vec<u32>> = HashMap::new();
+    let mut result = 0;
+
+    for (index_row, line) in schematic.rows().enumerate() {
+        if keep_current_part {
+            match gear_map.entry((current_gear[0], current_gear[1])) {
+                Vacant(m) => {
+                    m.insert(vec![current_part]);
+                }
+                Occupied(mut m) => {
+                    m.get_mut().push(current_part);
+                }
+            }
+        }
+        keep_current_part = false;
+        current_part = 0;
+        for (index_col, char) in line.iter().enumerate() {
+            if char.is_ascii_digit() {
+                current_part *= 10;
+                current_part += char.to_digit(10).unwrap();
+
+                for (lookup_index_col, lookup_index_row) in
+                    schematic.neighbours8(index_col, index_row)
+                {
+                    let lookup_char = schematic[(lookup_index_col, lookup_index_row)];
+                    if lookup_char == '*' {
+                        keep_current_part = true;
+                        current_gear[0] = lookup_index_row.try_into().unwrap();
+                        current_gear[1] = lookup_index_col.try_into().unwrap();
+                    }
+                }
+            } else {
+                if keep_current_part {
+                    match gear_map.entry((current_gear[0], current_gear[1])) {
+                        Vacant(m) => {
+                            m.insert(vec![current_part]);
+                        }
+                        Occupied(mut m) => {
+                            m.get_mut().push(current_part);
+                        }
+                    }
+                }
+                keep_current_part = false;
+                current_part = 0;
+            }
+        }
+    }
+    if keep_current_part {
+        match gear_map.entry((current_gear[0], current_gear[1])) {
+            Vacant(m) => {
+                m.insert(vec![current_part]);
+            }
+            Occupied(mut m) => {
+                m.get_mut().push(current_part);
+            }
+        }
+    }
+
+    for (_, part_vec) in gear_map.into_iter() {
+        if part_vec.len() == 2 {
+            result += part_vec[0] * part_vec[1];
+        }
+    }
+    result
+}
+
+/// Sum of the numbers adjacent to a symbol.
+pub fn sum_part_number(schematic: &Grid<char>) -> u32 {
+    let mut current_part = 0;
+    let mut keep_current_part = false;
+    let mut result = 0;
+
+    for (index_row, line) in schematic.rows().enumerate() {
+        if keep_current_part {
+            result += current_part;
+            current_part = 0;
+            keep_current_part = false;
+        }
+        for (index_col, char) in line.iter().enumerate() {
+            if char.is_ascii_digit() {
+                current_part *= 10;
+                current_part += char.to_digit(10).unwrap();
+
+                for (lookup_index_col, lookup_index_row) in
+                    schematic.neighbours8(index_col, index_row)
+                {
+                    let lookup_char = schematic[(lookup_index_col, lookup_index_row)];
+                    if !(lookup_char.is_ascii_digit() || lookup_char == '.') {
+                        keep_current_part = true;
+                    }
+                }
+            } else {
+                if keep_current_part {
+                    result += current_part;
+                }
+                current_part = 0;
+                keep_current_part = false;
+            }
+        }
+    }
+    if keep_current_part {
+        result += current_part;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests_day03 {
+    use super::*;
+
+    #[test]
+    fn test_sum_part_number() {
+        assert_eq!(sum_part_number(&Day03.parse("467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..").unwrap()), 4361);
+        assert_eq!(sum_part_number(&Day03.parse("467*114...\n..........\n.^35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..").unwrap()), 4475);
+        assert_eq!(sum_part_number(&Day03.parse("12.......*..\n+.........34\n.......-12..\n..78........\n..*....60...\n78..........\n.......23...\n....90*12...\n............\n2.2......12.\n.*.........*\n1.1.......56").unwrap()), 413);
+        assert_eq!(
+            sum_part_number(
+                &Day03
+                    .parse("....................\n..-52..52-..52..52..\n..................-.")
+                    .unwrap()
+            ),
+            156
+        );
+        assert_eq!(sum_part_number(&Day03.parse("12.......*..\n+.........34\n.......-12..\n..78........\n..*....60...\n78.........9\n.5.....23..$\n8...90*12...\n............\n2.2......12.\n.*.........*\n1.1..503+.56").unwrap()), 925);
+        assert_eq!(sum_part_number(&Day03.parse("12\n24").unwrap()), 0);
+    }
+
+    #[test]
+    fn test_sum_gear_ratio() {
+        assert_eq!(sum_gear_ratio(&Day03.parse("467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..").unwrap()), 467835);
+        assert_eq!(sum_gear_ratio(&Day03.parse("12.......*..\n+.........34\n.......-12..\n..78........\n..*....60...\n78..........\n.......23...\n....90*12...\n............\n2.2......12.\n.*.........*\n1.1.......56").unwrap()), 6756);
+        assert_eq!(sum_gear_ratio(&Day03.parse("12.......*..\n+.........34\n.......-12..\n..78........\n..*....60...\n78.........9\n.5.....23..$\n8...90*12...\n............\n2.2......12.\n.*.........*\n1.1..503+.56").unwrap()), 6756);
+    }
+}
@@ -0,0 +1,162 @@
+use common::{parse_number, Answer, ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| Card::new(line).map_err(|err| err.within(input, line)))
+            .collect()
+    }
+
+    fn part_1(&self, cards: &Self::Input) -> Answer {
+        cards.iter().map(Card::points).sum::<u32>().into()
+    }
+
+    fn part_2(&self, cards: &Self::Input) -> Answer {
+        let mut scratchcards_count: Vec<u32> = Vec::new();
+        for (index, card) in cards.iter().enumerate() {
+            match scratchcards_count.get_mut(index) {
+                Some(card_num) => {
+                    *card_num += 1;
+                }
+                None => {
+                    scratchcards_count.push(1);
+                }
+            }
+            let curr_card_num = scratchcards_count[index];
+            let value = card.num_of_match_cards();
+            for offset in 1..=value {
+                match scratchcards_count.get_mut(index + offset) {
+                    Some(card_num) => {
+                        *card_num += curr_card_num;
+                    }
+                    None => {
+                        scratchcards_count.push(curr_card_num);
+                    }
+                }
+            }
+        }
+
+        scratchcards_count.iter().sum::<u32>().into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    win_cards: Vec<u32>,
+    cards: Vec<u32>,
+}
+impl Card {
+    pub fn new(line: &str) -> Result<Self, ParseError> {
+        let (_, numbers) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(line, line, "expected `Card <id>: ...`"))?;
+        let (win_cards, cards) = numbers.split_once('|').ok_or_else(|| {
+            ParseError::at(
+                line,
+                numbers,
+                "expected `|` between the two lists of numbers",
+            )
+        })?;
+        let parse_numbers = |slice: &str| {
+            slice
+                .split(' ')
+                .filter(|&f| !f.is_empty())
+                .map(|f| parse_number(line, f))
+                .collect::<Result<Vec<u32>, ParseError>>()
+        };
+        Ok(Self {
+            win_cards: parse_numbers(win_cards)?,
+            cards: parse_numbers(cards)?,
+        })
+    }
+
+    pub fn win_cards(&self) -> &[u32] {
+        &self.win_cards
+    }
+
+    pub fn cards(&self) -> &[u32] {
+        &self.cards
+    }
+
+    /// Number of cards that are also winning numbers.
+    pub fn num_of_match_cards(&self) -> usize {
+        self.cards
+            .iter()
+            .filter(|card| self.win_cards.contains(card))
+            .count()
+    }
+
+    pub fn points(&self) -> u32 {
+        match self.num_of_match_cards() {
+            0 => 0,
+            matches => 1 << (matches - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_day04 {
+    use super::*;
+    use common::Part;
+
+    #[test]
+    fn test_card_point() {
+        assert_eq!(
+            Card::new("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
+                .unwrap()
+                .points(),
+            8
+        );
+        assert_eq!(
+            Card::new("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19")
+                .unwrap()
+                .points(),
+            2
+        );
+        assert_eq!(
+            Card::new("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")
+                .unwrap()
+                .points(),
+            2
+        );
+        assert_eq!(
+            Card::new("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83")
+                .unwrap()
+                .points(),
+            1
+        );
+        assert_eq!(
+            Card::new("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36")
+                .unwrap()
+                .points(),
+            0
+        );
+        assert_eq!(
+            Card::new("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11")
+                .unwrap()
+                .points(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day04
+                .parse("Card 1: 41 | 83\nCard 2: 13 | 6l 30")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 14: expected a number, found `6l`"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day04.solve("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\nCard 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\nCard 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\nCard 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\nCard 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\nCard 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11", Part::Two), Ok(30.into()));
+    }
+}
@@ -0,0 +1,455 @@
+use common::{parse_number, split_blocks, Answer, ParseError, Solution};
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+/// Range of `length` numbers starting at `start_src`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CustomRange {
+    start_src: u64,
+    length: u64,
+}
+#[derive(Debug)]
+pub struct CustomRangeMatch {
+    range: CustomRange,
+    has_match: bool,
+}
+impl CustomRangeMatch {
+    pub fn range(&self) -> &CustomRange {
+        &self.range
+    }
+    /// Whether the range was mapped, or left unchanged because no mapping applies.
+    pub fn has_match(&self) -> bool {
+        self.has_match
+    }
+}
+impl CustomRange {
+    pub fn new(start_src: u64, length: u64) -> CustomRange {
+        CustomRange { start_src, length }
+    }
+    pub fn start_src(&self) -> u64 {
+        self.start_src
+    }
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+    pub fn end_src(&self) -> u64 {
+        self.start_src + self.length - 1
+    }
+
+    pub fn does_intersect(&self, range: &CustomRange) -> bool {
+        range.start_src >= self.start_src && range.start_src <= self.end_src()
+            || range.end_src() >= self.start_src && range.end_src() <= self.end_src()
+            || range.start_src <= self.start_src && range.end_src() >= self.end_src()
+    }
+    pub fn intersect(&self, range: &CustomRange) -> Option<CustomRange> {
+        if self.does_intersect(range) {
+            let start = std::cmp::max(self.start_src, range.start_src);
+            let end = std::cmp::min(self.end_src(), range.end_src());
+            Some(CustomRange {
+                start_src: start,
+                length: end - start + 1,
+            })
+        } else {
+            None
+        }
+    }
+    pub fn intersect_inverse(&self, range: &CustomRange) -> Vec<CustomRange> {
+        let mut not_intersect_vector: Vec<CustomRange> = Vec::new();
+
+        if self.start_src < range.start_src {
+            let end = std::cmp::min(range.start_src - 1, self.end_src());
+            not_intersect_vector.push(CustomRange {
+                start_src: self.start_src,
+                length: end - self.start_src + 1,
+            });
+        }
+        if self.end_src() > range.end_src() {
+            let start = std::cmp::max(self.start_src, range.end_src() + 1);
+            not_intersect_vector.push(CustomRange {
+                start_src: start,
+                length: self.end_src() - start + 1,
+            });
+        }
+        not_intersect_vector
+    }
+}
+
+/// Maps `length` numbers starting at `start_src` to the numbers starting at `start_dst`.
+#[derive(Copy, Clone, Debug)]
+pub struct CustomMapRange {
+    start_src: u64,
+    start_dst: u64,
+    length: u64,
+}
+impl CustomMapRange {
+    pub fn new(start_src: u64, start_dst: u64, length: u64) -> CustomMapRange {
+        CustomMapRange {
+            start_src,
+            start_dst,
+            length,
+        }
+    }
+    /// Parses a `<destination start> <source start> <length>` line.
+    pub fn from_str_input(input: &str) -> Result<CustomMapRange, ParseError> {
+        let num_split = input
+            .split(' ')
+            .filter(|f| !f.is_empty())
+            .map(|f| parse_number(input, f))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+        if num_split.len() != 3 {
+            return Err(ParseError::at(
+                input,
+                input,
+                format!("expected 3 numbers, found {}", num_split.len()),
+            ));
+        }
+        Ok(CustomMapRange::new(
+            num_split[1],
+            num_split[0],
+            num_split[2],
+        ))
+    }
+
+    pub fn get(&self, num_src: u64) -> Option<u64> {
+        if num_src >= self.start_src && num_src < self.start_src + self.length {
+            let distance = num_src - self.start_src;
+            Some(self.start_dst + distance)
+        } else {
+            None
+        }
+    }
+    pub fn get_range(&self, range: &CustomRange) -> Vec<CustomRangeMatch> {
+        let mut match_vec: Vec<CustomRangeMatch> = Vec::new();
+        let self_range = CustomRange::new(self.start_src, self.length);
+        if let Some(range_src_unwrap) = self_range.intersect(range) {
+            let distance = range_src_unwrap.start_src() - self.start_src;
+            match_vec.push(CustomRangeMatch {
+                range: CustomRange {
+                    start_src: self.start_dst + distance,
+                    length: range_src_unwrap.length,
+                },
+                has_match: true,
+            });
+            let unchange_ranges = range.intersect_inverse(&self_range);
+            for unchande_range in unchange_ranges.iter() {
+                match_vec.push(CustomRangeMatch {
+                    range: *unchande_range,
+                    has_match: false,
+                });
+            }
+        } else {
+            match_vec.push(CustomRangeMatch {
+                range: *range,
+                has_match: false,
+            });
+        }
+        match_vec
+    }
+}
+
+/// Union of map ranges, numbers outside of every range are mapped to themselves.
+#[derive(Clone, Debug)]
+pub struct CustomMultipleMapRange {
+    ranges: Vec<CustomMapRange>,
+}
+impl CustomMultipleMapRange {
+    pub fn new() -> CustomMultipleMapRange {
+        CustomMultipleMapRange { ranges: Vec::new() }
+    }
+
+    pub fn clear(&mut self) {
+        self.ranges.clear()
+    }
+
+    pub fn add_range(&mut self, new_range: CustomMapRange) {
+        self.ranges.push(new_range);
+    }
+
+    pub fn get(&self, num_src: u64) -> u64 {
+        for range in self.ranges.iter() {
+            let check_map = range.get(num_src);
+            if let Some(c) = check_map {
+                return c;
+            }
+        }
+        num_src
+    }
+
+    pub fn get_range(&self, range_src_key: &CustomRange) -> Vec<CustomRange> {
+        let mut ranges_src = vec![*range_src_key];
+        let mut res: Vec<CustomRange> = Vec::new();
+
+        let mut new_ranges_src: Vec<CustomRange> = Vec::new();
+        let mut match_vec: Vec<CustomRange> = Vec::new();
+        loop {
+            for range_src in new_ranges_src.iter() {
+                let mut has_match = true;
+                for range in self.ranges.iter() {
+                    let get_range = range.get_range(range_src);
+                    if get_range.len() == 1 && !get_range[0].has_match {
+                        has_match = false;
+                    } else {
+                        for match_range in get_range {
+                            if match_range.has_match {
+                                res.push(match_range.range);
+                            } else {
+                                match_vec.push(match_range.range);
+                            }
+                        }
+                        has_match = true;
+                        break;
+                    }
+                }
+                if !has_match {
+                    res.push(*range_src);
+                } else {
+                    ranges_src.append(&mut match_vec);
+                }
+                match_vec.clear(); // maybe useless!
+            }
+            new_ranges_src = ranges_src.clone();
+            ranges_src.clear();
+
+            if new_ranges_src.is_empty() {
+                break;
+            }
+        }
+
+        res
+    }
+}
+
+impl Default for CustomMultipleMapRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut blocks = split_blocks(input).into_iter();
+        let seeds_block = blocks.next().unwrap_or_default();
+        let mut seeds_lines = seeds_block.lines();
+        let seeds_line = seeds_lines.next().unwrap_or_default();
+        if let Some(line) = seeds_lines.next() {
+            return Err(ParseError::at(
+                input,
+                line,
+                "expected an empty line after the seeds",
+            ));
+        }
+        let seeds = seeds_line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::at(input, seeds_line, "expected `seeds: ...`"))?
+            .split(' ')
+            .filter(|f| !f.is_empty())
+            .map(|f| parse_number(input, f))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+
+        let mut maps = Vec::new();
+        for block in blocks {
+            let mut lines = block.lines();
+            let header = lines.next().unwrap_or_default();
+            if !header.ends_with(" map:") {
+                return Err(ParseError::at(
+                    input,
+                    header,
+                    "expected `<source>-to-<destination> map:`",
+                ));
+            }
+            let mut map = CustomMultipleMapRange::new();
+            for line in lines {
+                let range =
+                    CustomMapRange::from_str_input(line).map_err(|err| err.within(input, line))?;
+                map.add_range(range);
+            }
+            maps.push(map);
+        }
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part_1(&self, almanac: &Self::Input) -> Answer {
+        let mut last_src = almanac.seeds.clone();
+        for map in almanac.maps.iter() {
+            last_src = last_src.iter().map(|f| map.get(*f)).collect();
+        }
+
+        (*last_src.iter().min().unwrap()).into()
+    }
+
+    fn part_2(&self, almanac: &Self::Input) -> Answer {
+        let seeds = &almanac.seeds;
+        let mut seeds_ranges: Vec<CustomRange> = Vec::new();
+        let mut i = 0;
+        loop {
+            if i >= seeds.len() - 1 {
+                break;
+            }
+            seeds_ranges.push(CustomRange::new(seeds[i], seeds[i + 1]));
+            i += 2;
+        }
+
+        let mut last_src = seeds_ranges;
+        for map in almanac.maps.iter() {
+            last_src = last_src.iter().flat_map(|f| map.get_range(f)).collect();
+        }
+
+        let start_range: Vec<u64> = last_src.iter().map(|f| f.start_src).collect();
+        start_range.into_iter().min().unwrap().into()
+    }
+}
+
+/// Seeds and the chain of maps from seed to location.
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<CustomMultipleMapRange>,
+}
+impl Almanac {
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
+    pub fn maps(&self) -> &[CustomMultipleMapRange] {
+        &self.maps
+    }
+}
+
+#[cfg(test)]
+mod tests_day05 {
+    use super::*;
+    use common::{check_property, Rng};
+
+    #[test]
+    fn test_custom_map_range() {
+        let map = CustomMapRange::from_str_input("50 98 2").unwrap();
+        let map2 = CustomMapRange::from_str_input("52 50 48").unwrap();
+        assert_eq!(map.get(98).unwrap(), 50);
+        assert_eq!(map2.get(79).unwrap(), 81);
+        assert!(map.get(14).is_none());
+        let res = map.get_range(&CustomRange::new(96, 6));
+        assert_eq!(res.len(), 3);
+        assert_eq!(res[0].range.length, 2);
+        assert_eq!(res[1].range.length, 2);
+        assert_eq!(res[2].range.length, 2);
+    }
+
+    #[test]
+    fn test_multiple_map_range() {
+        let mut map = CustomMultipleMapRange { ranges: Vec::new() };
+        map.add_range(CustomMapRange::from_str_input("50 98 2").unwrap());
+        map.add_range(CustomMapRange::from_str_input("52 50 48").unwrap());
+        assert_eq!(map.get(55), 57);
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(14), 14);
+        assert_eq!(map.get(13), 13);
+    }
+
+    #[test]
+    fn test_range_get() {
+        let mut map = CustomMultipleMapRange { ranges: Vec::new() };
+        map.add_range(CustomMapRange::from_str_input("50 90 5").unwrap());
+        map.add_range(CustomMapRange::from_str_input("55 95 5").unwrap());
+        let res = map.get_range(&CustomRange {
+            start_src: 90,
+            length: 10,
+        });
+        assert_eq!(res.len(), 2);
+        let res = map.get_range(&CustomRange {
+            start_src: 88,
+            length: 14,
+        });
+        assert_eq!(res.len(), 4);
+        assert_eq!(res[0].start_src, 50);
+        let res = map.get_range(&CustomRange {
+            start_src: 92,
+            length: 10,
+        });
+        assert_eq!(res[0].start_src, 52);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98\n";
+        assert_eq!(
+            Day05.parse(input).err().map(|err| err.to_string()),
+            Some("line 4, column 1: expected 3 numbers, found 2".to_string())
+        );
+        let input = "seeds: 79 14\n\n50 98 2\n";
+        assert_eq!(
+            Day05.parse(input).err().map(|err| err.to_string()),
+            Some("line 3, column 1: expected `<source>-to-<destination> map:`".to_string())
+        );
+    }
+
+    fn random_range(rng: &mut Rng) -> CustomRange {
+        CustomRange::new(rng.range(0..=30) as u64, rng.range(1..=15) as u64)
+    }
+
+    fn contains(range: &CustomRange, number: u64) -> bool {
+        (range.start_src()..=range.end_src()).contains(&number)
+    }
+
+    #[test]
+    fn test_intersect_partition() {
+        check_property(500, |rng| {
+            let (range, other) = (random_range(rng), random_range(rng));
+            let inside = range.intersect(&other);
+            let outside = range.intersect_inverse(&other);
+            let pieces: Vec<_> = inside.iter().chain(outside.iter()).collect();
+            assert!(pieces.iter().all(|piece| piece.length() > 0));
+            assert_eq!(
+                pieces.iter().map(|piece| piece.length()).sum::<u64>(),
+                range.length()
+            );
+            for number in range.start_src()..=range.end_src() {
+                let count = pieces
+                    .iter()
+                    .filter(|piece| contains(piece, number))
+                    .count();
+                assert_eq!(count, 1, "{number} in {range:?} and {other:?}");
+                let is_inside = inside.is_some_and(|inside| contains(&inside, number));
+                assert_eq!(is_inside, contains(&other, number));
+            }
+        });
+    }
+
+    #[test]
+    fn test_map_get_range() {
+        check_property(500, |rng| {
+            let mut map = CustomMultipleMapRange::new();
+            let mut start = 0;
+            while start < 60 {
+                let length = rng.range(1..=10) as u64;
+                if rng.chance(0.7) {
+                    map.add_range(CustomMapRange::new(start, rng.range(0..=60) as u64, length));
+                }
+                start += length;
+            }
+            let range = CustomRange::new(rng.range(0..=50) as u64, rng.range(1..=20) as u64);
+
+            let mut expected: Vec<u64> = (range.start_src()..=range.end_src())
+                .map(|number| map.get(number))
+                .collect();
+            let mut mapped: Vec<u64> = map
+                .get_range(&range)
+                .iter()
+                .flat_map(|range| range.start_src()..=range.end_src())
+                .collect();
+            expected.sort();
+            mapped.sort();
+            assert_eq!(mapped, expected, "{range:?} through {map:?}");
+        });
+    }
+
+    #[test]
+    fn test_reference_part2() {
+        let input = include_str!("../../aoc-2023-inputs/day-05/test.txt");
+        let almanac = Day05.parse(input).unwrap();
+        assert_eq!(reference::part_2(&almanac), 46);
+    }
+}
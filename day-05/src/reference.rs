@@ -0,0 +1,19 @@
+//! Slow but straightforward solution, to check [`Day05`](crate::Day05) against.
+
+use crate::Almanac;
+
+/// Lowest location of a seed, mapping every seed of every range one by one.
+pub fn part_2(almanac: &Almanac) -> u64 {
+    almanac
+        .seeds
+        .chunks_exact(2)
+        .flat_map(|pair| pair[0]..pair[0] + pair[1])
+        .map(|seed| {
+            almanac
+                .maps
+                .iter()
+                .fold(seed, |number, map| map.get(number))
+        })
+        .min()
+        .unwrap()
+}
@@ -0,0 +1,150 @@
+use common::{parse_number, Answer, ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Races {
+            races: Race::parse_from_str(input)?,
+            single_race: Race::parse_from_str_part2(input)?,
+        })
+    }
+
+    fn part_1(&self, races: &Self::Input) -> Answer {
+        races
+            .races
+            .iter()
+            .map(Race::num_of_win)
+            .product::<u64>()
+            .into()
+    }
+
+    fn part_2(&self, races: &Self::Input) -> Answer {
+        races.single_race.num_of_win().into()
+    }
+}
+
+/// Both readings of the sheet: one race per column, or a single race with the spaces removed.
+pub struct Races {
+    races: Vec<Race>,
+    single_race: Race,
+}
+impl Races {
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+    pub fn single_race(&self) -> &Race {
+        &self.single_race
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Race {
+    pub duration: u64,
+    pub best_score: u64,
+}
+impl Race {
+    pub fn new(duration: u64, best_score: u64) -> Race {
+        Race {
+            duration,
+            best_score,
+        }
+    }
+    /// Number of ways to beat the best score.
+    pub fn num_of_win(&self) -> u64 {
+        let mut num_of_win = 0;
+        for start_time in 1..self.duration {
+            // speed = start_time * 1
+            let score = (self.duration - start_time) * start_time;
+            if score > self.best_score {
+                num_of_win += 1;
+            }
+        }
+        num_of_win
+    }
+
+    pub fn parse_from_str(input: &str) -> Result<Vec<Race>, ParseError> {
+        let [(_, durations), (_, best_scores)] = parse_sheet(input)?;
+        Ok(durations
+            .into_iter()
+            .zip(best_scores)
+            .map(|(duration, best_score)| Race::new(duration, best_score))
+            .collect())
+    }
+
+    pub fn parse_from_str_part2(input: &str) -> Result<Race, ParseError> {
+        let [(durations, _), (best_scores, _)] = parse_sheet(input)?;
+        let join = |values: &str| {
+            values
+                .split_whitespace()
+                .collect::<String>()
+                .parse::<u64>()
+                .map_err(|_| ParseError::at(input, values, "number is too large once joined"))
+        };
+        Ok(Race::new(join(durations)?, join(best_scores)?))
+    }
+}
+
+/// Values of the `Time:` and `Distance:` lines, raw and parsed.
+fn parse_sheet(input: &str) -> Result<[(&str, Vec<u64>); 2], ParseError> {
+    let mut lines = input.lines();
+    let mut parse_line = |label: &str| {
+        let line = lines.next().unwrap_or(&input[input.len()..]);
+        let values = line
+            .strip_prefix(label)
+            .ok_or_else(|| ParseError::at(input, line, format!("expected `{label} ...`")))?;
+        let numbers = values
+            .split_whitespace()
+            .map(|f| parse_number(input, f))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+        Ok((values, numbers))
+    };
+    let durations = parse_line("Time:")?;
+    let best_scores = parse_line("Distance:")?;
+    if durations.1.len() != best_scores.1.len() {
+        return Err(ParseError::at(
+            input,
+            best_scores.0,
+            format!(
+                "expected {} distances, found {}",
+                durations.1.len(),
+                best_scores.1.len()
+            ),
+        ));
+    }
+    Ok([durations, best_scores])
+}
+
+#[cfg(test)]
+mod tests_day06 {
+    use super::*;
+
+    #[test]
+    fn test_parsing_input() {
+        let input = include_str!("../../aoc-2023-inputs/day-06/test.txt");
+        let races = Race::parse_from_str(input).unwrap();
+        assert_eq!(races.len(), 3);
+        assert_eq!(races[0].duration, 7);
+        assert_eq!(races[2].duration, 30);
+        assert_eq!(races[2].best_score, 200);
+    }
+
+    #[test]
+    fn test_parsing_input_part2() {
+        let input = include_str!("../../aoc-2023-inputs/day-06/test.txt");
+        let race = Race::parse_from_str_part2(input).unwrap();
+        assert_eq!(race.duration, 71530);
+        assert_eq!(race.best_score, 940200);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Race::parse_from_str("Time: 7 15\nDistance: 9").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 10: expected 2 distances, found 1"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use common::{read_input, Solution};
+use day_07::Day07;
+
+fn main() {
+    let input = read_input(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../aoc-2023-inputs/day-07/input.txt"
+    ))
+    .expect("input file not found, use `aoc run 7 --input PATH` instead");
+    let input = Day07
+        .parse(&input)
+        .unwrap_or_else(|err| panic!("invalid input: {}", err.diagnostic(&input)));
+    dbg!(Day07.part_1(&input));
+}
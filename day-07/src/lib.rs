@@ -0,0 +1,40 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Hands;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut hands = Hands {
+            part1: Vec::new(),
+            part2: Vec::new(),
+        };
+        for line in input.lines() {
+            let locate = |err: ParseError| err.within(input, line);
+            hands.part1.push(part1::Hand::new(line).map_err(locate)?);
+            hands.part2.push(part2::Hand::new(line).map_err(locate)?);
+        }
+        if hands.part1.is_empty() {
+            return Err(ParseError::new(1, 1, "expected a hand"));
+        }
+        Ok(hands)
+    }
+
+    fn part_1(&self, hands: &Self::Input) -> Answer {
+        part1::total_winnings(&hands.part1).into()
+    }
+
+    fn part_2(&self, hands: &Self::Input) -> Answer {
+        part2::total_winnings(&hands.part2).into()
+    }
+}
+
+/// Hands read with the regular card order (part 1) and with `J` as joker (part 2).
+pub struct Hands {
+    pub part1: Vec<part1::Hand>,
+    pub part2: Vec<part2::Hand>,
+}
@@ -1,13 +1,12 @@
-use std::{cmp::Ordering, collections::HashMap};
+//! Camel Cards with `J` as a jack.
 
-fn main() {
-    let input = include_str!("../../aoc-2023-inputs/day-07/input.txt");
-    dbg!(part_1(input));
-}
+use common::{parse_number, ParseError};
+use std::{cmp::Ordering, collections::HashMap};
 
-fn part_1(input: &str) -> u64 {
-    let mut hands = input.lines().map(Hand::from_str).collect::<Vec<Hand>>();
-    hands.sort_by(|a, b| a.cmp(b));
+/// Sum of the bids multiplied by the rank of their hand.
+pub fn total_winnings(hands: &[Hand]) -> u64 {
+    let mut hands = hands.iter().collect::<Vec<&Hand>>();
+    hands.sort();
     hands
         .into_iter()
         .map(|f| f.bid)
@@ -18,7 +17,7 @@ fn part_1(input: &str) -> u64 {
 }
 
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Hash)]
-enum Card {
+pub enum Card {
     A = 13,
     K = 12,
     Q = 11,
@@ -54,12 +53,13 @@ impl Card {
     }
 }
 
-struct Hand {
-    cards: Vec<Card>,
-    bid: u64,
+/// Five cards and the bid of the hand, ordered by strength.
+pub struct Hand {
+    pub cards: Vec<Card>,
+    pub bid: u64,
 }
 #[derive(Debug, PartialEq, PartialOrd)]
-enum HandType {
+pub enum HandType {
     HighCard = 1,
     OnePair = 2,
     TwoPairs = 3,
@@ -69,16 +69,28 @@ enum HandType {
     FiveOfAKind = 7,
 }
 impl Hand {
-    pub fn from_str(s: &str) -> Hand {
-        let split = s.split(' ').collect::<Vec<&str>>();
+    /// Parses a `<cards> <bid>` line.
+    pub fn new(s: &str) -> Result<Hand, ParseError> {
+        let (labels, bid) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(s, s, "expected `<cards> <bid>`"))?;
         let mut cards = Vec::new();
-        for c in split[0].chars() {
-            cards.push(Card::from_char(&c).unwrap());
+        for (i, c) in labels.char_indices() {
+            let card = Card::from_char(&c)
+                .ok_or_else(|| ParseError::at(s, &s[i..], format!("unknown card `{c}`")))?;
+            cards.push(card);
         }
-        Hand {
-            cards,
-            bid: split[1].parse::<u64>().unwrap(),
+        if cards.len() != 5 {
+            return Err(ParseError::at(
+                s,
+                labels,
+                format!("expected 5 cards, found {}", cards.len()),
+            ));
         }
+        Ok(Hand {
+            cards,
+            bid: parse_number(s, bid)?,
+        })
     }
     pub fn sorted(&self) -> Hand {
         let mut new_hand = Hand {
@@ -135,8 +147,9 @@ impl Hand {
             HandType::HighCard
         }
     }
-
-    pub fn cmp(&self, b: &Hand) -> Ordering {
+}
+impl Ord for Hand {
+    fn cmp(&self, b: &Hand) -> Ordering {
         let a_hand = self.hand_type();
         let b_hand = b.hand_type();
 
@@ -154,57 +167,69 @@ impl Hand {
         }
     }
 }
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Hand {}
 
 #[cfg(test)]
 mod tests_day07_01 {
     use super::*;
+    use crate::Day07;
+    use common::Solution;
 
     #[test]
     fn test_parsing_input() {
         let input = "32T3K 0";
-        let hand = Hand::from_str(input);
+        let hand = Hand::new(input).unwrap();
         assert_eq!(hand.cards.len(), 5);
         let hand_sorted = hand.sorted();
         assert_eq!(hand_sorted.cards[0], Card::N2);
         assert_eq!(hand_sorted.cards[1], Card::N3);
         assert_eq!(hand_sorted.cards[3], Card::T);
         assert_eq!(hand_sorted.cards[4], Card::K);
+
+        let error = Day07.parse("32T3K 765\n32X3K 684").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, column 3: unknown card `X`");
+        let error = Day07.parse("").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, column 1: expected a hand");
     }
 
     #[test]
     fn test_hand_type() {
         let input = "32T3K 0";
-        let hand = Hand::from_str(input);
+        let hand = Hand::new(input).unwrap();
         assert_eq!(hand.hand_type(), HandType::OnePair);
 
         let input = "AA8AA 0";
-        let hand = Hand::from_str(input);
+        let hand = Hand::new(input).unwrap();
         assert_eq!(hand.hand_type(), HandType::FourOfAKind);
 
         let input = "K33KK 0";
-        let hand = Hand::from_str(input);
+        let hand = Hand::new(input).unwrap();
         assert_eq!(hand.hand_type(), HandType::FullHouse);
 
         let input = "K8K4K 0";
-        let hand = Hand::from_str(input);
+        let hand = Hand::new(input).unwrap();
         assert_eq!(hand.hand_type(), HandType::ThreeOfAKind);
 
         let input = "KTK44 0";
-        let hand = Hand::from_str(input);
+        let hand = Hand::new(input).unwrap();
         assert_eq!(hand.hand_type(), HandType::TwoPairs);
 
         let input = "A2345 0";
-        let hand = Hand::from_str(input);
+        let hand = Hand::new(input).unwrap();
         assert_eq!(hand.hand_type(), HandType::HighCard);
 
         let input = "KKKKK 0";
-        let hand = Hand::from_str(input);
+        let hand = Hand::new(input).unwrap();
         assert_eq!(hand.hand_type(), HandType::FiveOfAKind);
     }
-
-    #[test]
-    fn test_part1() {
-        let input = include_str!("../../aoc-2023-inputs/day-07/test.txt");
-        assert_eq!(part_1(input), 6440);
-    }
 }
@@ -0,0 +1,158 @@
+use common::{Answer, ParseError, Solution};
+use num::integer::lcm;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines_iter = input.lines();
+        let path_line = lines_iter.next().unwrap_or_default();
+        if path_line.is_empty() {
+            return Err(ParseError::new(
+                1,
+                1,
+                "expected the left/right instructions",
+            ));
+        }
+        if let Some((i, c)) = path_line
+            .char_indices()
+            .find(|(_, c)| !matches!(c, 'L' | 'R'))
+        {
+            return Err(ParseError::at(
+                input,
+                &path_line[i..],
+                format!("expected `L` or `R`, found `{c}`"),
+            ));
+        }
+        let path = path_line.chars().collect::<Vec<char>>();
+        if let Some(line) = lines_iter.next().filter(|line| !line.is_empty()) {
+            return Err(ParseError::at(input, line, "expected an empty line"));
+        }
+
+        let re = Regex::new(r"^([0-9-A-Z]{3}) = \(([0-9-A-Z]{3}), ([0-9-A-Z]{3})\)$").unwrap();
+        let mut map: HashMap<String, Direction> = HashMap::new();
+        let mut destinations = Vec::new();
+        for line in lines_iter {
+            let (_, [name, left, right]) = re
+                .captures(line)
+                .map(|c| c.extract())
+                .ok_or_else(|| ParseError::at(input, line, "expected `AAA = (BBB, CCC)`"))?;
+            let direction = Direction::new(left.to_string(), right.to_string());
+            map.insert(name.to_string(), direction);
+            destinations.extend([left, right]);
+        }
+        if let Some(unknown) = destinations
+            .into_iter()
+            .find(|name| !map.contains_key(*name))
+        {
+            return Err(ParseError::at(
+                input,
+                unknown,
+                format!("unknown node `{unknown}`"),
+            ));
+        }
+        Ok(Network { path, map })
+    }
+
+    fn part_1(&self, network: &Self::Input) -> Answer {
+        let Network { path, map } = network;
+        let mut current_point = "AAA";
+        let mut path_index = 0;
+        let mut count_iter: u32 = 0;
+        while current_point != "ZZZ" {
+            let direction = map.get(current_point).unwrap();
+            if path[path_index] == 'L' {
+                current_point = &direction.left;
+            } else {
+                current_point = &direction.right;
+            }
+            count_iter += 1;
+            path_index = (path_index + 1) % path.len();
+        }
+        count_iter.into()
+    }
+
+    fn part_2(&self, network: &Self::Input) -> Answer {
+        let Network { path, map } = network;
+        let starting_points: Vec<&str> = map
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(String::as_str)
+            .collect();
+        let mut cycle_length: Vec<u64> = Vec::new();
+        for start_point in starting_points.iter() {
+            let mut current_point = *start_point;
+            let mut path_index = 0;
+            let mut count_iter = 0;
+            loop {
+                let direction = map.get(current_point).unwrap();
+                if path[path_index] == 'L' {
+                    current_point = &direction.left;
+                } else {
+                    current_point = &direction.right;
+                }
+                count_iter += 1;
+                path_index = (path_index + 1) % path.len();
+                if current_point.ends_with('Z') {
+                    break;
+                }
+            }
+            cycle_length.push(count_iter);
+        }
+        cycle_length.into_iter().reduce(lcm).unwrap().into()
+    }
+}
+
+/// Left/right instructions and the nodes they are followed on.
+pub struct Network {
+    path: Vec<char>,
+    map: HashMap<String, Direction>,
+}
+impl Network {
+    pub fn path(&self) -> &[char] {
+        &self.path
+    }
+    pub fn node(&self, name: &str) -> Option<&Direction> {
+        self.map.get(name)
+    }
+    pub fn node_names(&self) -> impl Iterator<Item = &str> {
+        self.map.keys().map(String::as_str)
+    }
+}
+
+/// Nodes reached by going left or right.
+pub struct Direction {
+    pub left: String,
+    pub right: String,
+}
+impl Direction {
+    pub fn new(left: String, right: String) -> Self {
+        Self { left, right }
+    }
+}
+
+#[cfg(test)]
+mod tests_day08 {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        let error = Day08.parse(input).err().unwrap();
+        assert_eq!(error.to_string(), "line 3, column 8: unknown node `BBB`");
+    }
+
+    #[test]
+    fn test_reference_part2() {
+        let input = include_str!("../../aoc-2023-inputs/day-08/test3.txt");
+        let network = Day08.parse(input).unwrap();
+        assert_eq!(reference::part_2(&network), 6);
+    }
+}
@@ -0,0 +1,27 @@
+//! Slow but straightforward solution, to check [`Day08`](crate::Day08) against.
+
+use crate::Network;
+
+/// Steps until every ghost is on a node ending with `Z`, moving all of them one step at a time.
+pub fn part_2(network: &Network) -> u64 {
+    let mut positions: Vec<&str> = network
+        .node_names()
+        .filter(|name| name.ends_with('A'))
+        .collect();
+    let mut steps = 0;
+    for instruction in network.path.iter().cycle() {
+        if positions.iter().all(|position| position.ends_with('Z')) {
+            break;
+        }
+        for position in positions.iter_mut() {
+            let direction = &network.map[*position];
+            *position = if *instruction == 'L' {
+                &direction.left
+            } else {
+                &direction.right
+            };
+        }
+        steps += 1;
+    }
+    steps
+}
@@ -0,0 +1,81 @@
+use common::{parse_number, Answer, ParseError, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                if line.trim().is_empty() {
+                    return Err(ParseError::at(input, line, "expected a history of values"));
+                }
+                line.split_whitespace()
+                    .map(|n| parse_number(input, n))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_1(&self, histories: &Self::Input) -> Answer {
+        let mut projected_values: Vec<i64> = Vec::new();
+        for history in histories.iter() {
+            let project_table = project_table(history);
+            projected_values.push(project_table.into_iter().map(|a| *a.last().unwrap()).sum())
+        }
+        projected_values.iter().sum::<i64>().into()
+    }
+
+    fn part_2(&self, histories: &Self::Input) -> Answer {
+        let mut projected_values: Vec<i64> = Vec::new();
+        for history in histories.iter() {
+            let project_table = project_table(history);
+            projected_values.push(
+                project_table
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, a)| {
+                        let val = *a.first().unwrap();
+                        if index % 2 == 0 {
+                            val
+                        } else {
+                            -val
+                        }
+                    })
+                    .sum(),
+            )
+        }
+        projected_values.iter().sum::<i64>().into()
+    }
+}
+
+/// Successive differences of the history, until they are all zeros.
+pub fn project_table(history: &[i64]) -> Vec<Vec<i64>> {
+    let mut project_table = vec![history.to_vec()];
+    while project_table.last().unwrap().iter().any(|f| *f != 0) {
+        let diff: Vec<i64> = project_table
+            .last()
+            .unwrap()
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect();
+        project_table.push(diff);
+    }
+    project_table
+}
+
+#[cfg(test)]
+mod test_day09 {
+    use crate::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day09.parse("0 3 6\n1 3 x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a number, found `x`"
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use common::{Answer, Direction, Grid, ParseError, Point2, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut map = Map::new(input)?;
+        map.set_start_open_directions()?;
+        Ok(map)
+    }
+
+    fn part_1(&self, map: &Self::Input) -> Answer {
+        let mut map = map.clone();
+        let path_length = map.define_loop();
+        ((path_length - 1) / 2).into()
+    }
+
+    fn part_2(&self, map: &Self::Input) -> Answer {
+        let mut map = map.clone();
+        map.define_loop();
+        map.count_tiles_inside().into()
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Pipe {
+    Horizontal,
+    Vertical,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+    Ground,
+    Start,
+}
+impl Pipe {
+    pub fn new(c: char) -> Option<Self> {
+        match c {
+            '-' => Some(Self::Horizontal),
+            '|' => Some(Self::Vertical),
+            'L' => Some(Self::NorthEast),
+            'J' => Some(Self::NorthWest),
+            'F' => Some(Self::SouthEast),
+            '7' => Some(Self::SouthWest),
+            '.' => Some(Self::Ground),
+            'S' => Some(Self::Start),
+            _ => None,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub struct Tile {
+    pub pipe: Pipe,
+    pub position: Point2<usize>,
+    /// Vertical direction of the loop going through this tile, once the loop is defined.
+    pub loop_dir: Option<Direction>,
+    pub open_directions: [Option<Direction>; 2],
+}
+impl Tile {
+    pub fn new(pipe: Pipe, position: Point2<usize>) -> Self {
+        let open_directions = match &pipe {
+            Pipe::Horizontal => [Direction::East, Direction::West].map(Some),
+            Pipe::Vertical => [Direction::North, Direction::South].map(Some),
+            Pipe::NorthEast => [Direction::North, Direction::East].map(Some),
+            Pipe::NorthWest => [Direction::North, Direction::West].map(Some),
+            Pipe::SouthEast => [Direction::South, Direction::East].map(Some),
+            Pipe::SouthWest => [Direction::South, Direction::West].map(Some),
+            Pipe::Ground | Pipe::Start => [None, None],
+        };
+        Self {
+            pipe,
+            position,
+            loop_dir: None,
+            open_directions,
+        }
+    }
+
+    pub fn is_adjacent(&self, position: Point2<usize>) -> bool {
+        self.position.manhattan_distance(position) == 1
+    }
+
+    /// Direction going from this tile to the adjacent `position`.
+    pub fn relative_direction(&self, position: Point2<usize>) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|dir| self.position.step(*dir) == Some(position))
+    }
+
+    pub fn is_open_to(&self, position: Point2<usize>) -> bool {
+        self.relative_direction(position)
+            .is_some_and(|dir| self.open_directions.contains(&Some(dir)))
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+impl Map {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let pipes = Grid::parse(input, Pipe::new)?;
+        let tiles = Grid::from_fn(pipes.width(), pipes.height(), |x, y| {
+            Tile::new(pipes[(x, y)], Point2::new(x, y))
+        });
+        Ok(Self { tiles })
+    }
+
+    pub fn width(&self) -> usize {
+        self.tiles.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.tiles.height()
+    }
+
+    /// Opens the start tile toward the pipes connected to it, which must be exactly two.
+    pub fn set_start_open_directions(&mut self) -> Result<(), ParseError> {
+        let starts: Vec<Point2<usize>> = self
+            .tiles
+            .iter()
+            .filter(|(_, t)| t.pipe == Pipe::Start)
+            .map(|(_, t)| t.position)
+            .collect();
+        let start_position = *starts
+            .first()
+            .ok_or_else(|| ParseError::new(1, 1, "expected a start tile `S`"))?;
+        if let Some(other) = starts.get(1) {
+            return Err(ParseError::new(
+                other.y + 1,
+                other.x + 1,
+                "expected a single start tile `S`",
+            ));
+        }
+        let adjacent_tiles: Vec<Tile> = start_position
+            .neighbours()
+            .filter_map(|position| self.get_tile(position).copied())
+            .collect();
+        let start = &mut self.tiles[start_position];
+
+        for adjacent_tile in adjacent_tiles.iter() {
+            if adjacent_tile.is_open_to(start.position) {
+                if start.open_directions[0].is_none() {
+                    start.open_directions[0] = start.relative_direction(adjacent_tile.position);
+                    if start.open_directions[0].is_some_and(Direction::is_vertical) {
+                        start.loop_dir = start.open_directions[0];
+                    }
+                } else if start.open_directions[1].is_none() {
+                    start.open_directions[1] = start.relative_direction(adjacent_tile.position);
+                } else {
+                    start.open_directions[0] = None;
+                }
+            }
+        }
+        if start.open_directions.contains(&None) {
+            return Err(ParseError::new(
+                start.position.y + 1,
+                start.position.x + 1,
+                "expected the start tile to be connected to exactly two pipes",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Marks the tiles of the loop going through the start, returns its length.
+    pub fn define_loop(&mut self) -> u32 {
+        let self_copy = self.clone();
+        let start = self_copy
+            .tiles
+            .iter()
+            .map(|(_, t)| t)
+            .find(|t| t.pipe == Pipe::Start)
+            .unwrap();
+
+        let mut current_tile = start;
+        let mut next_dir = start.open_directions[0].unwrap();
+        let mut last_next_dir = next_dir;
+        let mut path_length = 1;
+        loop {
+            let loop_dir = if next_dir.is_vertical() {
+                next_dir
+            } else {
+                last_next_dir
+            };
+            self.tiles[current_tile.position].loop_dir = Some(loop_dir);
+            last_next_dir = next_dir;
+            let next_position = current_tile.position.step(next_dir).unwrap();
+            let next_tile = self_copy.get_tile(next_position).unwrap();
+
+            path_length += 1;
+            next_dir = next_tile
+                .open_directions
+                .into_iter()
+                .flatten()
+                .find(|dir| Some(*dir) != next_tile.relative_direction(current_tile.position))
+                .unwrap();
+            current_tile = next_tile;
+            if current_tile.pipe == Pipe::Start {
+                let loop_dir = if next_dir.is_vertical() {
+                    next_dir
+                } else {
+                    last_next_dir
+                };
+                self.tiles[current_tile.position].loop_dir = Some(loop_dir);
+                break;
+            }
+        }
+        path_length
+    }
+
+    pub fn count_tiles_inside(&self) -> u32 {
+        let mut tile_count = 0;
+        for row in self.tiles.rows() {
+            let mut is_inside = false;
+            let mut last_loop_dir = None;
+            for tile in row {
+                if let Some(loop_dir) = tile.loop_dir {
+                    if !loop_dir.is_vertical() {
+                        continue;
+                    }
+                    if last_loop_dir != Some(loop_dir) {
+                        is_inside = !is_inside;
+                    }
+                    last_loop_dir = Some(loop_dir);
+                } else if is_inside {
+                    tile_count += 1;
+                }
+            }
+        }
+        tile_count
+    }
+
+    pub fn get_tile(&self, position: Point2<usize>) -> Option<&Tile> {
+        self.tiles.get(position.x, position.y)
+    }
+
+    pub fn get_tile_mut(&mut self, position: Point2<usize>) -> Option<&mut Tile> {
+        self.tiles.get_mut(position.x, position.y)
+    }
+}
+
+#[cfg(test)]
+mod test_day10 {
+    use crate::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day10
+            .parse(".....\n.S-7.\n.|.|.\n.L-X.\n.....")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 4: unexpected character `X`"
+        );
+        let error = Day10
+            .parse(".|...\n.S-7.\n.|.|.\n.L-J.\n.....")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected the start tile to be connected to exactly two pipes"
+        );
+    }
+}
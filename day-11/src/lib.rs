@@ -0,0 +1,180 @@
+use common::{Answer, Grid, ParseError, Solution};
+
+pub struct Day11 {
+    /// Number of rows or columns replacing each empty one in part 2.
+    pub expansion: u32,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self { expansion: 1000000 }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Map::new(input)
+    }
+
+    fn part_1(&self, map: &Self::Input) -> Answer {
+        let mut map = map.clone();
+        map.replace_expansion();
+
+        let galaxies = map.find_all_galaxy();
+        sum_distances(&galaxies).into()
+    }
+
+    fn part_2(&self, map: &Self::Input) -> Answer {
+        let galaxies = map.find_all_galaxy_with_big_expansion(self.expansion);
+        sum_distances(&galaxies).into()
+    }
+}
+
+/// Sum of the distances between every pair of galaxies.
+pub fn sum_distances(galaxies: &[(usize, usize)]) -> u64 {
+    let mut distance_sum = 0;
+    for (i, galaxy) in galaxies.iter().enumerate() {
+        for j in galaxies.iter().skip(i + 1) {
+            distance_sum += distance(galaxy, j);
+        }
+    }
+    distance_sum
+}
+
+/// Manhattan distance between two galaxies.
+pub fn distance(galaxy1: &(usize, usize), galaxy2: &(usize, usize)) -> u64 {
+    let (x1, y1) = galaxy1;
+    let (x2, y2) = galaxy2;
+    let x = (*x1 as i64 - *x2 as i64).abs();
+    let y = (*y1 as i64 - *y2 as i64).abs();
+    (x + y) as u64
+}
+
+#[derive(PartialEq, Clone)]
+pub enum Galaxy {
+    Empty,
+    Galaxy,
+}
+impl Galaxy {
+    pub fn new(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '#' => Some(Self::Galaxy),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone)]
+pub struct Map {
+    map: Grid<Galaxy>,
+}
+impl Map {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Grid::parse(input, Galaxy::new)?,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.map.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    pub fn detect_empty_line(&self) -> Vec<usize> {
+        let mut empty_lines = Vec::new();
+        for (y, line) in self.map.rows().enumerate() {
+            if line.iter().all(|galaxy| *galaxy == Galaxy::Empty) {
+                empty_lines.push(y);
+            }
+        }
+        empty_lines
+    }
+    pub fn detect_empty_column(&self) -> Vec<usize> {
+        let mut empty_columns = Vec::new();
+        for (x, mut column) in self.map.columns().enumerate() {
+            if column.all(|galaxy| *galaxy == Galaxy::Empty) {
+                empty_columns.push(x);
+            }
+        }
+        empty_columns
+    }
+
+    /// Doubles every empty row and column.
+    pub fn replace_expansion(&mut self) {
+        let empty_lines = self.detect_empty_line();
+        let empty_columns = self.detect_empty_column();
+
+        let mut rows = Vec::new();
+        for (y, line) in self.map.rows().enumerate() {
+            let mut row = Vec::new();
+            for (x, galaxy) in line.iter().enumerate() {
+                row.push(galaxy.clone());
+                if empty_columns.contains(&x) {
+                    row.push(Galaxy::Empty);
+                }
+            }
+            if empty_lines.contains(&y) {
+                rows.push(row.clone());
+            }
+            rows.push(row);
+        }
+        self.map = Grid::from_rows(rows);
+    }
+
+    pub fn find_all_galaxy(&self) -> Vec<(usize, usize)> {
+        let mut galaxies = Vec::new();
+        for ((x, y), galaxy) in self.map.iter() {
+            if *galaxy == Galaxy::Galaxy {
+                galaxies.push((x, y));
+            }
+        }
+        galaxies
+    }
+
+    /// Galaxies coordinates when each empty row and column is replaced by `expansion` ones.
+    pub fn find_all_galaxy_with_big_expansion(&self, expansion: u32) -> Vec<(usize, usize)> {
+        let expansion = expansion - 1;
+        let empty_lines = self.detect_empty_line();
+        let empty_columns = self.detect_empty_column();
+        let mut galaxies = Vec::new();
+
+        let mut number_of_line_cross = 0;
+        for (y, line) in self.map.rows().enumerate() {
+            if empty_lines.contains(&y) {
+                number_of_line_cross += 1;
+                continue;
+            }
+            let mut number_of_col_cross = 0;
+            for (x, galaxy) in line.iter().enumerate() {
+                if empty_columns.contains(&x) {
+                    number_of_col_cross += 1;
+                    continue;
+                }
+                if *galaxy == Galaxy::Galaxy {
+                    let x = x + number_of_col_cross as usize * expansion as usize;
+                    let y = y + number_of_line_cross as usize * expansion as usize;
+                    galaxies.push((x, y));
+                }
+            }
+        }
+        galaxies
+    }
+}
+
+#[cfg(test)]
+mod test_day11 {
+    use crate::*;
+
+    #[test]
+    fn test_emptylines() {
+        let input = include_str!("../../aoc-2023-inputs/day-11/test.txt");
+        let map = Map::new(input).unwrap();
+        assert_eq!(map.detect_empty_line(), vec![3, 7]);
+        assert_eq!(map.detect_empty_column(), vec![2, 5, 8]);
+    }
+}
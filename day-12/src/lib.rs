@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use common::{parse_number, Answer, ParseError, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| Record::new(line).map_err(|err| err.within(input, line)))
+            .collect()
+    }
+
+    fn part_1(&self, records: &Self::Input) -> Answer {
+        records
+            .iter()
+            .map(Record::count_arrangements)
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part_2(&self, records: &Self::Input) -> Answer {
+        records
+            .iter()
+            .map(|record| record.unfold().count_arrangements())
+            .sum::<u64>()
+            .into()
+    }
+}
+
+/// Condition record of a row of springs.
+#[derive(Debug, Clone)]
+pub struct Record {
+    springs: Vec<char>,
+    checksums: Vec<usize>,
+}
+impl Record {
+    /// Parses a `<springs> <group sizes>` line.
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (springs, checksums) = input
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(input, input, "expected `<springs> <groups>`"))?;
+        if let Some((i, c)) = springs
+            .char_indices()
+            .find(|(_, c)| !matches!(c, '.' | '#' | '?'))
+        {
+            return Err(ParseError::at(
+                input,
+                &springs[i..],
+                format!("unknown spring `{c}`"),
+            ));
+        }
+        let checksums = checksums
+            .split(',')
+            .map(|n| parse_number(input, n))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+        Ok(Self {
+            springs: springs.chars().collect(),
+            checksums,
+        })
+    }
+
+    /// Five copies of the record, springs separated by `?`.
+    pub fn unfold(&self) -> Self {
+        let springs = self.springs.iter().collect::<String>();
+        let springs = [springs.as_str(); 5].join("?");
+        Self {
+            springs: springs.chars().collect(),
+            checksums: self.checksums.repeat(5),
+        }
+    }
+
+    pub fn springs(&self) -> &[char] {
+        &self.springs
+    }
+
+    pub fn checksums(&self) -> &[usize] {
+        &self.checksums
+    }
+
+    /// Number of ways to replace the `?` so the damaged springs groups match the checksums.
+    pub fn count_arrangements(&self) -> u64 {
+        let mut pos: HashMap<usize, usize> = HashMap::new();
+        pos.insert(0, 1);
+        for (index, checksum) in self.checksums.iter().enumerate() {
+            let mut new_pos: HashMap<usize, usize> = HashMap::new();
+            for (k, v) in pos.iter() {
+                let filter_checksums = &self.checksums[(index + 1)..];
+                let filter_checksums_sum: usize = filter_checksums.iter().sum();
+                let filter_checksums_count: usize = filter_checksums.len();
+                let max_length = self.springs.len() + filter_checksums_count - filter_checksums_sum;
+                for n in (*k)..max_length {
+                    if (n + checksum - 1) < self.springs.len()
+                        && !self.springs[n..(n + *checksum)].contains(&'.')
+                        && ((index == (self.checksums.len() - 1)
+                            && !self.springs[(n + *checksum)..].contains(&'#'))
+                            || (index < (self.checksums.len() - 1)
+                                && (n + checksum) < self.springs.len()
+                                && self.springs[n + checksum] != '#'))
+                    {
+                        new_pos.insert(
+                            n + checksum + 1,
+                            if new_pos.contains_key(&(n + checksum + 1)) {
+                                new_pos[&(n + checksum + 1)] + *v
+                            } else {
+                                *v
+                            },
+                        );
+                    }
+                    if self.springs[n] == '#' {
+                        break;
+                    }
+                }
+            }
+            pos = new_pos;
+        }
+        pos.values().map(|v| *v as u64).sum()
+    }
+}
+
+#[cfg(test)]
+mod test_day12 {
+    use crate::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day12.parse("???.### 1,1,3\n.??..?x 1,1").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 7: unknown spring `x`");
+    }
+
+    #[test]
+    fn test_arrangment() {
+        assert_eq!(
+            Record::new("???.### 1,1,3").unwrap().count_arrangements(),
+            1
+        );
+        assert_eq!(
+            Record::new(".??..??...?##. 1,1,3")
+                .unwrap()
+                .count_arrangements(),
+            4
+        );
+        assert_eq!(
+            Record::new("?#?#?#?#?#?#?#? 1,3,1,6")
+                .unwrap()
+                .count_arrangements(),
+            1
+        );
+        assert_eq!(
+            Record::new("????.#...#... 4,1,1")
+                .unwrap()
+                .count_arrangements(),
+            1
+        );
+        assert_eq!(
+            Record::new("????.######..#####. 1,6,5")
+                .unwrap()
+                .count_arrangements(),
+            4
+        );
+        assert_eq!(
+            Record::new("?###???????? 3,2,1")
+                .unwrap()
+                .count_arrangements(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_arrangment2() {
+        assert_eq!(
+            Record::new("???.### 1,1,3")
+                .unwrap()
+                .unfold()
+                .count_arrangements(),
+            1
+        );
+        assert_eq!(
+            Record::new(".??..??...?##. 1,1,3")
+                .unwrap()
+                .unfold()
+                .count_arrangements(),
+            16384
+        );
+        assert_eq!(
+            Record::new("?#?#?#?#?#?#?#? 1,3,1,6")
+                .unwrap()
+                .unfold()
+                .count_arrangements(),
+            1
+        );
+        assert_eq!(
+            Record::new("????.#...#... 4,1,1")
+                .unwrap()
+                .unfold()
+                .count_arrangements(),
+            16
+        );
+        assert_eq!(
+            Record::new("????.######..#####. 1,6,5")
+                .unwrap()
+                .unfold()
+                .count_arrangements(),
+            2500
+        );
+        assert_eq!(
+            Record::new("?###???????? 3,2,1")
+                .unwrap()
+                .unfold()
+                .count_arrangements(),
+            506250
+        );
+    }
+}
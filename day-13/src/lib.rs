@@ -0,0 +1,236 @@
+use common::{split_blocks, Answer, Grid, ParseError, Solution};
+use std::cmp::min;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pattern>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        split_blocks(input)
+            .into_iter()
+            .map(|block| parse_pattern(block).map_err(|err| err.within(input, block)))
+            .collect()
+    }
+
+    fn part_1(&self, patterns: &Self::Input) -> Answer {
+        let mirrors = patterns.iter().map(find_mirror).collect::<Vec<Mirror>>();
+        summarize(&mirrors).into()
+    }
+
+    fn part_2(&self, patterns: &Self::Input) -> Answer {
+        let mirrors = patterns
+            .iter()
+            .map(find_diff_mirror)
+            .collect::<Vec<Mirror>>();
+        summarize(&mirrors).into()
+    }
+}
+
+pub type Pattern = Grid<char>;
+
+pub fn parse_pattern(input: &str) -> Result<Pattern, ParseError> {
+    Grid::parse(input, |c| matches!(c, '#' | '.').then_some(c))
+}
+
+/// Columns left of vertical mirrors plus 100 times the rows above horizontal mirrors.
+pub fn summarize(mirrors: &[Mirror]) -> u64 {
+    let mut result = 0;
+    result += mirrors
+        .iter()
+        .filter(|m| m.m_type == MirrorType::Vertical)
+        .map(|m| m.pos)
+        .sum::<usize>() as u64;
+    result += mirrors
+        .iter()
+        .filter(|m| m.m_type == MirrorType::Horizontal)
+        .map(|m| m.pos * 100)
+        .sum::<usize>() as u64;
+    result
+}
+
+/// Line of reflection, `pos` rows or columns are before it.
+pub struct Mirror {
+    pub pos: usize,
+    pub m_type: MirrorType,
+}
+#[derive(Debug, PartialEq)]
+pub enum MirrorType {
+    Vertical,
+    Horizontal,
+}
+
+pub fn find_mirror(map: &Pattern) -> Mirror {
+    let mut is_vertical = false;
+    let mut pos = 0;
+    for i in 1..map.height() {
+        pos = i;
+        for j in 0..min(i, map.height() - i) {
+            if map.row(i + j) == map.row(i - (j + 1)) {
+                is_vertical = true;
+                continue;
+            } else {
+                is_vertical = false;
+                break;
+            }
+        }
+        if is_vertical {
+            break;
+        }
+    }
+    if is_vertical {
+        Mirror {
+            pos,
+            m_type: MirrorType::Horizontal,
+        }
+    } else {
+        let map = map.transpose();
+
+        let mut is_horizontal = false;
+        let mut pos = 0;
+        for i in 1..map.height() {
+            pos = i;
+            for j in 0..min(i, map.height() - i) {
+                if map.row(i + j) == map.row(i - (j + 1)) {
+                    is_horizontal = true;
+                    continue;
+                } else {
+                    is_horizontal = false;
+                    break;
+                }
+            }
+            if is_horizontal {
+                break;
+            }
+        }
+
+        Mirror {
+            pos,
+            m_type: MirrorType::Vertical,
+        }
+    }
+}
+
+/// Whether both lines differ by exactly one character.
+pub fn cmp_diff_mirror(vec1: &[char], vec2: &[char]) -> bool {
+    let mut num_of_diff = 0;
+    for i in 0..vec1.len() {
+        if vec1[i] != vec2[i] {
+            num_of_diff += 1;
+        }
+    }
+    num_of_diff == 1
+}
+
+/// Finds the mirror once the single smudge of the pattern is fixed.
+pub fn find_diff_mirror(map: &Pattern) -> Mirror {
+    let mut is_vertical = false;
+    let mut pos = 0;
+    for i in 1..map.height() {
+        let mut num_of_diff = 0;
+        pos = i;
+        for j in 0..min(i, map.height() - i) {
+            if map.row(i + j) == map.row(i - (j + 1)) {
+                is_vertical = true;
+            } else if num_of_diff == 0 && cmp_diff_mirror(map.row(i + j), map.row(i - (j + 1))) {
+                num_of_diff += 1;
+                is_vertical = true;
+            } else {
+                is_vertical = false;
+                break;
+            }
+        }
+        if is_vertical {
+            if num_of_diff == 1 {
+                break;
+            }
+            is_vertical = false;
+        }
+    }
+    if is_vertical {
+        Mirror {
+            pos,
+            m_type: MirrorType::Horizontal,
+        }
+    } else {
+        let map = map.transpose();
+
+        let mut is_horizontal = false;
+        let mut pos = 0;
+        for i in 1..map.height() {
+            let mut num_of_diff = 0;
+            pos = i;
+            for j in 0..min(i, map.height() - i) {
+                if map.row(i + j) == map.row(i - (j + 1)) {
+                    is_horizontal = true;
+                } else if num_of_diff == 0 && cmp_diff_mirror(map.row(i + j), map.row(i - (j + 1)))
+                {
+                    num_of_diff += 1;
+                    is_horizontal = true;
+                } else {
+                    is_horizontal = false;
+                    break;
+                }
+            }
+            if is_horizontal {
+                if num_of_diff == 1 {
+                    break;
+                }
+                is_horizontal = false;
+            }
+        }
+
+        Mirror {
+            pos,
+            m_type: MirrorType::Vertical,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_day13 {
+    use crate::*;
+    use common::Part;
+
+    #[test]
+    fn test_find_mirror() {
+        let input = include_str!("../../aoc-2023-inputs/day-13/test.txt");
+        let result = find_mirror(&parse_pattern(input).unwrap());
+        assert_eq!(result.m_type, MirrorType::Vertical);
+        assert_eq!(result.pos, 5);
+
+        let input = include_str!("../../aoc-2023-inputs/day-13/test2.txt");
+        let result = find_mirror(&parse_pattern(input).unwrap());
+        assert_eq!(result.m_type, MirrorType::Horizontal);
+        assert_eq!(result.pos, 4);
+    }
+
+    #[test]
+    fn test_crlf_input() {
+        let input = include_str!("../../aoc-2023-inputs/day-13/test3.txt");
+        let input = format!("\u{feff}{}\r\n\r\n", input.replace('\n', "  \r\n"));
+        assert_eq!(Day13.solve(&input, Part::One), Ok(405.into()));
+    }
+
+    #[test]
+    fn test_find_diff_mirror() {
+        let input = include_str!("../../aoc-2023-inputs/day-13/test.txt");
+        let result = find_diff_mirror(&parse_pattern(input).unwrap());
+        assert_eq!(result.m_type, MirrorType::Horizontal);
+        assert_eq!(result.pos, 3);
+
+        let input = include_str!("../../aoc-2023-inputs/day-13/test2.txt");
+        let result = find_diff_mirror(&parse_pattern(input).unwrap());
+        assert_eq!(result.m_type, MirrorType::Horizontal);
+        assert_eq!(result.pos, 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day13.parse("#.#\n.#.\n\n##\n#o\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 2: unexpected character `o`"
+        );
+    }
+}
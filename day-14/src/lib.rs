@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use common::{Answer, Grid, ParseError, Solution};
+
+pub struct Day14 {
+    /// Number of spin cycles applied in part 2.
+    pub cycles: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self { cycles: 1000000000 }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, |c| matches!(c, 'O' | '#' | '.').then_some(c))
+    }
+
+    fn part_1(&self, map: &Self::Input) -> Answer {
+        let map = tilt_map_north(map.clone());
+        total_load(&map).into()
+    }
+
+    fn part_2(&self, map: &Self::Input) -> Answer {
+        let mut map = map.clone();
+        let mut cache_map: HashMap<Grid<char>, Grid<char>> = HashMap::new();
+
+        let mut cycle_map: Vec<Grid<char>> = Vec::new();
+        let mut start_cycle_index = 0;
+        for i in 0..self.cycles {
+            if cache_map.contains_key(&map) {
+                if cycle_map.is_empty() {
+                    start_cycle_index = i;
+                } else if map == *cycle_map.first().unwrap() {
+                    break;
+                }
+                cycle_map.push(map.clone());
+                map = cache_map.get(&map).unwrap().clone();
+            } else {
+                let new_map =
+                    tilt_map_east(tilt_map_south(tilt_map_west(tilt_map_north(map.clone()))));
+                cache_map.insert(map.clone(), new_map.clone());
+                map = new_map;
+            }
+        }
+        let cycle_index = (self.cycles - start_cycle_index) % cycle_map.len();
+
+        total_load(&cycle_map[cycle_index]).into()
+    }
+}
+
+/// Load on the north support beams, each rock weighs its distance to the south edge.
+pub fn total_load(map: &Grid<char>) -> u64 {
+    map.rows()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter().filter(|c| **c == 'O').count() as u64 * (map.height() - i) as u64
+        })
+        .sum::<u64>()
+}
+
+/// Rolls every rounded rock as far north as it can go.
+pub fn tilt_map_north(map: Grid<char>) -> Grid<char> {
+    let mut map = map;
+    let mut lowest_location_north = vec![0; map.width()];
+    for i in 0..map.height() {
+        for j in 0..map.width() {
+            match map[(j, i)] {
+                '#' => lowest_location_north[j] = i + 1,
+                'O' => {
+                    map[(j, i)] = '.';
+                    map[(j, lowest_location_north[j])] = 'O';
+                    lowest_location_north[j] += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    map
+}
+pub fn tilt_map_west(map: Grid<char>) -> Grid<char> {
+    tilt_map_north(map.transpose()).transpose()
+}
+pub fn tilt_map_south(map: Grid<char>) -> Grid<char> {
+    let mut map = map;
+    let mut lowest_location_north = vec![map.height() - 1; map.width()];
+    for i in (0..map.height()).rev() {
+        for j in 0..map.width() {
+            match map[(j, i)] {
+                '#' => lowest_location_north[j] = if i > 0 { i - 1 } else { 0 },
+                'O' => {
+                    map[(j, i)] = '.';
+                    map[(j, lowest_location_north[j])] = 'O';
+                    if lowest_location_north[j] > 0 {
+                        lowest_location_north[j] -= 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    map
+}
+pub fn tilt_map_east(map: Grid<char>) -> Grid<char> {
+    tilt_map_south(map.transpose()).transpose()
+}
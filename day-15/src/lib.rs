@@ -0,0 +1,170 @@
+use common::{parse_number, Answer, ParseError, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim_end_matches(['\r', '\n'])
+            .split(',')
+            .map(|step| Step::new(step).map_err(|err| err.within(input, step)))
+            .collect()
+    }
+
+    fn part_1(&self, steps: &Self::Input) -> Answer {
+        steps.iter().map(|s| hash(&s.raw)).sum::<u64>().into()
+    }
+
+    fn part_2(&self, steps: &Self::Input) -> Answer {
+        let mut box_: Vec<Box> = Vec::with_capacity(256);
+        for _ in 0..256 {
+            box_.push(Box::new());
+        }
+
+        for step in steps {
+            match &step.operation {
+                Operation::Remove(label) => {
+                    let index = hash(label) as usize;
+                    box_[index].remove_lens(label);
+                }
+                Operation::Insert(lens) => {
+                    let index = hash(lens.label.as_str()) as usize;
+                    box_[index].add_lens(lens.clone());
+                }
+            }
+        }
+
+        box_.iter()
+            .enumerate()
+            .map(|(i, b)| {
+                b.lenses
+                    .iter()
+                    .enumerate()
+                    .map(|(j, l)| (i + 1) * (j + 1) * l.focal_length as usize)
+                    .sum::<usize>()
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+/// One step of the initialization sequence, kept raw for the hash of part 1.
+#[derive(Debug)]
+pub struct Step {
+    raw: String,
+    operation: Operation,
+}
+impl Step {
+    /// Parses a `<label>-` or `<label>=<focal length>` step.
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        let operation = if let Some(label) = s.strip_suffix('-') {
+            Operation::Remove(label.to_string())
+        } else if let Some((label, focal_length)) = s.split_once('=') {
+            Operation::Insert(Lens::new(label, parse_number(s, focal_length)?))
+        } else {
+            return Err(ParseError::at(
+                s,
+                s,
+                format!("expected `<label>-` or `<label>=<focal length>`, found `{s}`"),
+            ));
+        };
+        if let Some((i, c)) = s
+            .char_indices()
+            .take_while(|(_, c)| !matches!(c, '-' | '='))
+            .find(|(_, c)| !c.is_ascii_alphabetic())
+        {
+            return Err(ParseError::at(
+                s,
+                &s[i..],
+                format!("unexpected character `{c}` in label"),
+            ));
+        }
+        Ok(Self {
+            raw: s.to_string(),
+            operation,
+        })
+    }
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+    pub fn operation(&self) -> &Operation {
+        &self.operation
+    }
+}
+#[derive(Debug)]
+pub enum Operation {
+    Remove(String),
+    Insert(Lens),
+}
+
+/// HASH algorithm of the Lava Production Facility.
+pub fn hash(input: &str) -> u64 {
+    let mut hash = 0;
+    for c in input.chars() {
+        hash += c as u64;
+        hash *= 17;
+        hash %= 256;
+    }
+    hash
+}
+
+#[derive(Debug, Clone)]
+pub struct Lens {
+    pub label: String,
+    pub focal_length: u8,
+}
+impl Lens {
+    pub fn new(label: &str, focal_length: u8) -> Self {
+        Self {
+            label: label.to_string(),
+            focal_length,
+        }
+    }
+}
+#[derive(Debug)]
+struct Box {
+    lenses: Vec<Lens>,
+}
+impl Box {
+    fn new() -> Self {
+        Self { lenses: vec![] }
+    }
+    fn add_lens(&mut self, lens: Lens) {
+        let lens_with_label = self.lenses.iter_mut().find(|l| l.label == lens.label);
+        if let Some(lens_with_label) = lens_with_label {
+            lens_with_label.focal_length = lens.focal_length;
+        } else {
+            self.lenses.push(lens);
+        }
+    }
+    fn remove_lens(&mut self, label: &str) {
+        self.lenses.retain(|l| l.label != label);
+    }
+}
+
+#[cfg(test)]
+mod tests_day15 {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day15.parse("rn=1,cm-,qp=x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 13: expected a number, found `x`"
+        );
+        let error = Day15.parse("rn=1,c m-").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 7: unexpected character ` ` in label"
+        );
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("rn=1"), 30);
+        assert_eq!(hash("pc-"), 48);
+    }
+}
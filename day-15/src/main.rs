@@ -1,108 +1,15 @@
-fn main() {
-    let input = include_str!("../../aoc-2023-inputs/day-15/input.txt");
-    dbg!(part_1(input));
-    dbg!(part_2(input));
-}
-
-fn part_1(input: &str) -> u64 {
-    input.split(',').map(hash).sum()
-}
-
-fn part_2(input: &str) -> u64 {
-    let mut box_: Vec<Box> = Vec::with_capacity(256);
-    for _ in 0..256 {
-        box_.push(Box::new());
-    }
-
-    for s in input.split(',') {
-        if s.contains('-') {
-            let label = s.replace('-', "");
-            let index = hash(label.as_str()) as usize;
-            box_[index].remove_lens(label.as_str());
-        } else {
-            let sp_arr: Vec<&str> = s.split('=').collect();
-            let lens = Lens::new(sp_arr[0], sp_arr[1].parse::<u8>().unwrap());
-            let hash = hash(lens.label.as_str());
-            let index = hash as usize;
-            box_[index].add_lens(lens);
-        }
-    }
-
-    box_.iter()
-        .enumerate()
-        .map(|(i, b)| {
-            b.lenses
-                .iter()
-                .enumerate()
-                .map(|(j, l)| (i + 1) * (j + 1) * l.focal_length as usize)
-                .sum::<usize>()
-        })
-        .sum::<usize>() as u64
-}
-
-fn hash(input: &str) -> u64 {
-    let mut hash = 0;
-    for c in input.chars() {
-        hash += c as u64;
-        hash *= 17;
-        hash %= 256;
-    }
-    hash
-}
+use common::{read_input, Solution};
+use day_15::Day15;
 
-#[derive(Debug)]
-struct Lens {
-    label: String,
-    focal_length: u8,
-}
-impl Lens {
-    fn new(label: &str, focal_length: u8) -> Self {
-        Self {
-            label: label.to_string(),
-            focal_length,
-        }
-    }
-}
-#[derive(Debug)]
-struct Box {
-    lenses: Vec<Lens>,
-}
-impl Box {
-    fn new() -> Self {
-        Self { lenses: vec![] }
-    }
-    fn add_lens(&mut self, lens: Lens) {
-        let lens_with_label = self.lenses.iter_mut().find(|l| l.label == lens.label);
-        if lens_with_label.is_some() {
-            lens_with_label.unwrap().focal_length = lens.focal_length;
-        } else {
-            self.lenses.push(lens);
-        }
-    }
-    fn remove_lens(&mut self, label: &str) {
-        self.lenses.retain(|l| l.label != label);
-    }
-}
-
-#[cfg(test)]
-mod tests_day15 {
-    use super::*;
-
-    #[test]
-    fn test_hash() {
-        assert_eq!(hash("rn=1"), 30);
-        assert_eq!(hash("pc-"), 48);
-    }
-
-    #[test]
-    fn test_part_1() {
-        let input = include_str!("../../aoc-2023-inputs/day-15/test.txt");
-        assert_eq!(part_1(input), 1320);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = include_str!("../../aoc-2023-inputs/day-15/test.txt");
-        assert_eq!(part_2(input), 145);
-    }
+fn main() {
+    let input = read_input(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../aoc-2023-inputs/day-15/input.txt"
+    ))
+    .expect("input file not found, use `aoc run 15 --input PATH` instead");
+    let input = Day15
+        .parse(&input)
+        .unwrap_or_else(|err| panic!("invalid input: {}", err.diagnostic(&input)));
+    dbg!(Day15.part_1(&input));
+    dbg!(Day15.part_2(&input));
 }
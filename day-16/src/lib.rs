@@ -0,0 +1,172 @@
+use common::{Answer, Direction, Grid, ParseError, Point2, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<TileType>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, TileType::new)
+    }
+
+    fn part_1(&self, layout: &Self::Input) -> Answer {
+        energize(layout, Beam::new(Point2::new(0, 0), Direction::East)).into()
+    }
+
+    fn part_2(&self, layout: &Self::Input) -> Answer {
+        let mut max_energy = 0;
+        let width = layout.width();
+        let height = layout.height();
+
+        for start_dir in Direction::ALL {
+            for start_x in 0..width {
+                for start_y in 0..height {
+                    if (start_x != 0 && start_x != width - 1)
+                        && (start_y != 0 && start_y != height - 1)
+                    {
+                        continue;
+                    }
+                    let energy =
+                        energize(layout, Beam::new(Point2::new(start_x, start_y), start_dir));
+                    if energy > max_energy {
+                        max_energy = energy;
+                    }
+                }
+            }
+        }
+        max_energy.into()
+    }
+}
+
+/// Number of tiles energized by a beam entering the layout at `start`.
+pub fn energize(layout: &Grid<TileType>, start: Beam) -> u64 {
+    let mut map = layout.map(|t| Tile::new(*t));
+
+    let mut beams = vec![start];
+    loop {
+        if beams.is_empty() {
+            break;
+        }
+        let mut new_beams = vec![];
+        for beam in beams {
+            let tile = &mut map[beam.position];
+            if tile.already_been_there(&beam) {
+                continue;
+            }
+            tile.apply_transform(&beam)
+                .iter()
+                .filter_map(Beam::advance)
+                .filter(|b| map.contains(b.position.x, b.position.y))
+                .for_each(|b| new_beams.push(b));
+        }
+        beams = new_beams;
+    }
+    map.iter().filter(|(_, t)| t.is_energized).count() as u64
+}
+
+/// Head of a light beam on the tile at `position`.
+#[derive(Debug, Clone, Copy)]
+pub struct Beam {
+    pub position: Point2<usize>,
+    pub direction: Direction,
+}
+impl Beam {
+    pub fn new(position: Point2<usize>, direction: Direction) -> Self {
+        Self {
+            position,
+            direction,
+        }
+    }
+    fn turn(&self, direction: Direction) -> Self {
+        Self::new(self.position, direction)
+    }
+    fn advance(&self) -> Option<Self> {
+        Some(Self::new(
+            self.position.step(self.direction)?,
+            self.direction,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TileType {
+    Empty,
+    Horizontal,
+    Vertical,
+    DashRight,
+    DashLeft,
+}
+impl TileType {
+    pub fn new(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '-' => Some(Self::Horizontal),
+            '|' => Some(Self::Vertical),
+            '/' => Some(Self::DashRight),
+            '\\' => Some(Self::DashLeft),
+            _ => None,
+        }
+    }
+}
+struct Tile {
+    is_energized: bool,
+    tile_type: TileType,
+    beam_history: Vec<Direction>,
+}
+impl Tile {
+    fn new(tile_type: TileType) -> Self {
+        Self {
+            is_energized: false,
+            tile_type,
+            beam_history: vec![],
+        }
+    }
+    fn already_been_there(&self, beam: &Beam) -> bool {
+        self.beam_history.contains(&beam.direction)
+    }
+    fn apply_transform(&mut self, beam: &Beam) -> Vec<Beam> {
+        self.beam_history.push(beam.direction);
+        self.is_energized = true;
+        match self.tile_type {
+            TileType::Empty => vec![*beam],
+            TileType::Horizontal => match beam.direction {
+                Direction::North | Direction::South => {
+                    vec![beam.turn(Direction::West), beam.turn(Direction::East)]
+                }
+                _ => vec![*beam],
+            },
+            TileType::Vertical => match beam.direction {
+                Direction::West | Direction::East => {
+                    vec![beam.turn(Direction::North), beam.turn(Direction::South)]
+                }
+                _ => vec![*beam],
+            },
+            TileType::DashRight => match beam.direction {
+                Direction::East | Direction::West => vec![beam.turn(beam.direction.turn_left())],
+                Direction::North | Direction::South => {
+                    vec![beam.turn(beam.direction.turn_right())]
+                }
+            },
+            TileType::DashLeft => match beam.direction {
+                Direction::East | Direction::West => vec![beam.turn(beam.direction.turn_right())],
+                Direction::North | Direction::South => {
+                    vec![beam.turn(beam.direction.turn_left())]
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_day16 {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day16.parse(".|.\n./.\n.\\").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected 3 cells, found 2"
+        );
+    }
+}
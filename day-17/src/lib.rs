@@ -0,0 +1,142 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use common::{Answer, Direction, Grid, ParseError, Point2, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Map::new(input)
+    }
+
+    //Djikstra's algorithm
+    fn part_1(&self, map: &Self::Input) -> Answer {
+        map.compute_min_heat_loss(1, 3).into()
+    }
+
+    fn part_2(&self, map: &Self::Input) -> Answer {
+        map.compute_min_heat_loss(4, 10).into()
+    }
+}
+
+/// Crucible that just arrived at `pos` heading `direction`, ordered by lowest heat loss first.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct State {
+    pub pos: Point2<usize>,
+    pub direction: Direction,
+    pub heat_loss: u32,
+}
+impl State {
+    pub fn new(pos: Point2<usize>, direction: Direction, heat_loss: u32) -> Self {
+        Self {
+            pos,
+            direction,
+            heat_loss,
+        }
+    }
+}
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.heat_loss.cmp(&other.heat_loss) {
+            Ordering::Greater => std::cmp::Ordering::Less,
+            Ordering::Less => std::cmp::Ordering::Greater,
+            Ordering::Equal => (self.pos, self.direction).cmp(&(other.pos, other.direction)),
+        }
+    }
+}
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+pub struct Map {
+    grid: Grid<u8>,
+}
+impl Map {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            grid: Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))?,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    fn step_pos(&self, pos: Point2<usize>, dir: Direction) -> Option<Point2<usize>> {
+        pos.step(dir)
+            .filter(|new_pos| self.grid.contains(new_pos.x, new_pos.y))
+    }
+
+    /// Index of `state` in the table of the least heat loss per block and direction.
+    fn dist_index(&self, state: &State) -> usize {
+        (state.pos.y * self.grid.width() + state.pos.x) * 4 + state.direction as usize
+    }
+
+    /// States reachable by moving straight from `pos` between `min_length` and `max_length` blocks.
+    pub fn state_from_dir(
+        &self,
+        pos: Point2<usize>,
+        dir: Direction,
+        init_heat: u32,
+        min_length: usize,
+        max_length: usize,
+    ) -> Vec<State> {
+        let mut states = Vec::new();
+        let mut heat = init_heat;
+        let mut new_pos = self.step_pos(pos, dir);
+        for dist in 1..=max_length {
+            let Some(pos) = new_pos else {
+                break;
+            };
+            heat += self.grid[pos] as u32;
+            if dist >= min_length {
+                states.push(State::new(pos, dir, heat));
+            }
+            new_pos = self.step_pos(pos, dir);
+        }
+        states
+    }
+
+    /// Least heat loss from the top-left to the bottom-right block, using Dijkstra's algorithm.
+    pub fn compute_min_heat_loss(&self, min_length: usize, max_length: usize) -> u64 {
+        let mut dist = vec![u32::MAX; self.grid.width() * self.grid.height() * 4];
+        let mut heap = BinaryHeap::new();
+
+        //Add initiale states
+        for dir in [Direction::East, Direction::South] {
+            let states = self.state_from_dir(Point2::new(0, 0), dir, 0, min_length, max_length);
+            for state in states {
+                dist[self.dist_index(&state)] = state.heat_loss;
+                heap.push(state);
+            }
+        }
+
+        while let Some(state) = heap.pop() {
+            if state.heat_loss > dist[self.dist_index(&state)] {
+                continue;
+            }
+
+            let next_states =
+                [state.direction.turn_left(), state.direction.turn_right()].map(|dir| {
+                    self.state_from_dir(state.pos, dir, state.heat_loss, min_length, max_length)
+                });
+
+            for next_state in next_states.iter().flatten() {
+                let index = self.dist_index(next_state);
+                if next_state.heat_loss < dist[index] {
+                    dist[index] = next_state.heat_loss;
+                    heap.push(*next_state);
+                }
+            }
+        }
+        let goal = ((self.grid.width() * self.grid.height()) - 1) * 4;
+        *dist[goal..(goal + 4)].iter().min().unwrap() as u64
+    }
+}
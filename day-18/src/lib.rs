@@ -0,0 +1,273 @@
+use common::{parse_number, Answer, Direction, ParseError, Point2, Solution};
+use regex::Regex;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(DigPlan {
+            instructions: Instruction::parse_plan(input)?,
+            color_instructions: Instruction::parse_plan_color_correction(input)?,
+        })
+    }
+
+    //Shoelace formula
+    fn part_1(&self, plan: &Self::Input) -> Answer {
+        let map = Map::from_instructions(&plan.instructions);
+        map.count_volume().into()
+    }
+
+    fn part_2(&self, plan: &Self::Input) -> Answer {
+        let map = Map::from_instructions(&plan.color_instructions);
+        map.count_volume().into()
+    }
+}
+
+/// Dig plan read as written (part 1) and from the hexadecimal colors (part 2).
+pub struct DigPlan {
+    pub instructions: Vec<Instruction>,
+    pub color_instructions: Vec<Instruction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub steps: usize,
+}
+impl Instruction {
+    pub fn parse_plan(s: &str) -> Result<Vec<Self>, ParseError> {
+        parse_plan_lines(s)?
+            .into_iter()
+            .map(|(dir, len, _)| {
+                Ok(Instruction {
+                    direction: match dir {
+                        "U" => Direction::North,
+                        "R" => Direction::East,
+                        "D" => Direction::South,
+                        _ => Direction::West,
+                    },
+                    steps: parse_number(s, len)?,
+                })
+            })
+            .collect()
+    }
+    /// Reads the instructions hidden in the hexadecimal colors.
+    pub fn parse_plan_color_correction(s: &str) -> Result<Vec<Self>, ParseError> {
+        parse_plan_lines(s)?
+            .into_iter()
+            .map(|(_, _, color)| {
+                let direction = match &color[5..] {
+                    "0" => Direction::East,
+                    "1" => Direction::South,
+                    "2" => Direction::West,
+                    "3" => Direction::North,
+                    _ => {
+                        return Err(ParseError::at(
+                            s,
+                            &color[5..],
+                            "expected a direction between 0 and 3",
+                        ))
+                    }
+                };
+                Ok(Instruction {
+                    direction,
+                    steps: usize::from_str_radix(&color[0..5], 16).unwrap(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Direction, length and color of each `<U|R|D|L> <length> (#<color>)` line of the plan.
+fn parse_plan_lines(s: &str) -> Result<Vec<(&str, &str, &str)>, ParseError> {
+    let re = Regex::new(r"^([URDL]) ([0-9]+) \(#([0-9a-fA-F]{6})\)$").unwrap();
+    let lines = s
+        .lines()
+        .map(|line| {
+            let (_, [dir, len, color]) =
+                re.captures(line).map(|c| c.extract()).ok_or_else(|| {
+                    ParseError::at(s, line, "expected `<U|R|D|L> <length> (#<color>)`")
+                })?;
+            Ok((dir, len, color))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if lines.is_empty() {
+        return Err(ParseError::new(1, 1, "expected a dig instruction"));
+    }
+    Ok(lines)
+}
+/// Corners of the lagoon trench in digging order.
+#[derive(Debug, Clone)]
+pub struct Map {
+    nodes: Vec<Point2<i64>>,
+}
+impl Map {
+    pub fn from_instructions(instructions: &[Instruction]) -> Self {
+        let mut nodes = vec![];
+        let mut current = Point2::<i64>::new(0, 0);
+        for instruction in instructions.iter() {
+            nodes.push(current);
+            current = current
+                .step_n(instruction.direction, instruction.steps as i64)
+                .expect("the trench does not fit in i64 coordinates");
+        }
+        Map { nodes }
+    }
+
+    pub fn nodes(&self) -> &[Point2<i64>] {
+        &self.nodes
+    }
+
+    /// Cubic meters of lava held, from the shoelace formula and Pick's theorem.
+    pub fn count_volume(&self) -> u64 {
+        let (area, perimeter) =
+            self.nodes
+                .iter()
+                .enumerate()
+                .fold((0, 0), |(sum, perimeter), (i, node1)| {
+                    let node2 = &self.nodes[(i + 1) % self.nodes.len()];
+                    let new_perimeter = perimeter + node1.manhattan_distance(*node2);
+                    let new_sum = sum + node1.x * node2.y - node1.y * node2.x;
+                    (new_sum, new_perimeter)
+                });
+        ((area.unsigned_abs() + perimeter) / 2) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests_day18 {
+    use crate::*;
+    use common::{check_property, Rng};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_volume_count() {
+        let input = include_str!("../../aoc-2023-inputs/day-18/test2.txt");
+        let instr = Instruction::parse_plan(input).unwrap();
+        let map = Map::from_instructions(&instr);
+        assert_eq!(map.count_volume(), 49);
+    }
+
+    /// Corners of a random simple polygon made of columns side by side, each one overlapping the
+    /// previous one.
+    fn random_outline(rng: &mut Rng) -> Vec<(i64, i64)> {
+        let mut columns: Vec<(i64, i64, i64)> = Vec::new();
+        let mut x = 0;
+        for _ in 0..rng.range(1..=5) {
+            let (bottom, top) = loop {
+                let bottom = rng.range(0..=6);
+                let top = bottom + rng.range(1..=6);
+                match columns.last() {
+                    Some(&(_, b, t)) if bottom.max(b) >= top.min(t) => continue,
+                    _ => break (bottom, top),
+                }
+            };
+            columns.push((x, bottom, top));
+            x += rng.range(1..=4);
+        }
+
+        let mut corners = vec![(0, columns[0].1)];
+        let right = x;
+        for (i, &(x, _, top)) in columns.iter().enumerate() {
+            let end = columns.get(i + 1).map_or(right, |next| next.0);
+            corners.extend([(x, top), (end, top)]);
+        }
+        for (i, &(x, bottom, _)) in columns.iter().enumerate().rev() {
+            let end = columns.get(i + 1).map_or(right, |next| next.0);
+            corners.extend([(end, bottom), (x, bottom)]);
+        }
+        if rng.chance(0.5) {
+            corners.reverse();
+        }
+        if rng.chance(0.5) {
+            corners.iter_mut().for_each(|(x, y)| std::mem::swap(x, y));
+        }
+        corners
+    }
+
+    #[test]
+    fn test_volume_flood_fill() {
+        check_property(300, |rng| {
+            let corners = random_outline(rng);
+            let mut instructions = Vec::new();
+            for (from, to) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+                let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+                let direction = match (dx.signum(), dy.signum()) {
+                    (0, 0) => continue,
+                    (1, _) => Direction::East,
+                    (-1, _) => Direction::West,
+                    (_, 1) => Direction::South,
+                    _ => Direction::North,
+                };
+                let steps = (dx.abs() + dy.abs()) as usize;
+                instructions.push(Instruction { direction, steps });
+            }
+
+            let mut trench = HashSet::new();
+            let mut current = Point2::<i64>::new(0, 0);
+            for instruction in instructions.iter() {
+                for _ in 0..instruction.steps {
+                    current = current.step(instruction.direction).unwrap();
+                    trench.insert(current);
+                }
+            }
+            assert_eq!(current, Point2::new(0, 0));
+            let (min_x, max_x) = (
+                trench.iter().map(|p| p.x).min().unwrap() - 1,
+                trench.iter().map(|p| p.x).max().unwrap() + 1,
+            );
+            let (min_y, max_y) = (
+                trench.iter().map(|p| p.y).min().unwrap() - 1,
+                trench.iter().map(|p| p.y).max().unwrap() + 1,
+            );
+            let mut outside = HashSet::from([Point2::new(min_x, min_y)]);
+            let mut stack = vec![Point2::new(min_x, min_y)];
+            while let Some(point) = stack.pop() {
+                for next in point.neighbours() {
+                    if (min_x..=max_x).contains(&next.x)
+                        && (min_y..=max_y).contains(&next.y)
+                        && !trench.contains(&next)
+                        && outside.insert(next)
+                    {
+                        stack.push(next);
+                    }
+                }
+            }
+            let volume = (max_x - min_x + 1) * (max_y - min_y + 1) - outside.len() as i64;
+
+            let map = Map::from_instructions(&instructions);
+            assert_eq!(map.count_volume(), volume as u64, "{corners:?}");
+        });
+    }
+
+    #[test]
+    fn test_part_color_correction() {
+        let input = include_str!("../../aoc-2023-inputs/day-18/test.txt");
+        let instructions = Instruction::parse_plan_color_correction(input).unwrap();
+        assert_eq!(instructions.len(), 14);
+        assert_eq!(instructions[0].direction, Direction::East);
+        assert_eq!(instructions[0].steps, 461937);
+        assert_eq!(instructions[13].direction, Direction::North);
+        assert_eq!(instructions[13].steps, 500254);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Instruction::parse_plan("R 6 (#70c710)\nD 5 (#0dc571)\nX 2 (#5713f0)");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "line 3, column 1: expected `<U|R|D|L> <length> (#<color>)`"
+        );
+        let error = Instruction::parse_plan_color_correction("R 6 (#70c714)");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "line 1, column 12: expected a direction between 0 and 3"
+        );
+        assert_eq!(
+            Instruction::parse_plan("").unwrap_err().to_string(),
+            "line 1, column 1: expected a dig instruction"
+        );
+    }
+}
@@ -0,0 +1,526 @@
+use common::{parse_number, split_blocks, Answer, ParseError, Solution};
+use regex::Regex;
+use std::cmp::{max, min, Ordering};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut workflows = HashMap::new();
+        let mut pieces = Vec::new();
+        let mut destinations = Vec::new();
+        let re_wrk = Regex::new(r"^([a-z]+)\{(.+)\}$").unwrap();
+        let re_xmas = Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap();
+
+        let blocks = split_blocks(input);
+        let (workflows_block, pieces_block) = match blocks[..] {
+            [workflows] => (workflows, ""),
+            [workflows, pieces] => (workflows, pieces),
+            [_, _, extra, ..] => {
+                return Err(ParseError::at(
+                    input,
+                    extra,
+                    "expected workflows then pieces",
+                ))
+            }
+            [] => ("", ""),
+        };
+        for line in workflows_block.lines() {
+            let (_, [name, rules]) = re_wrk
+                .captures(line)
+                .map(|c| c.extract())
+                .ok_or_else(|| ParseError::at(input, line, "expected `<name>{<rules>}`"))?;
+            destinations.extend(rules.split(',').filter_map(|rule| rule.rsplit(':').next()));
+            workflows.insert(
+                name.to_string(),
+                Workflow {
+                    rules: Rule::parse_rules(rules).map_err(|err| err.within(input, rules))?,
+                },
+            );
+        }
+        for line in pieces_block.lines() {
+            let (_, xmas) = re_xmas.captures(line).map(|c| c.extract()).ok_or_else(|| {
+                ParseError::at(input, line, "expected `{x=<x>,m=<m>,a=<a>,s=<s>}`")
+            })?;
+            let [x, m, a, s] = xmas.map(|rating| parse_number(input, rating));
+            pieces.push(Piece::new(x?, m?, a?, s?));
+        }
+
+        if !workflows.contains_key("in") {
+            return Err(ParseError::new(1, 1, "expected a workflow named `in`"));
+        }
+        if let Some(unknown) = destinations
+            .into_iter()
+            .find(|name| !matches!(*name, "A" | "R") && !workflows.contains_key(*name))
+        {
+            return Err(ParseError::at(
+                input,
+                unknown,
+                format!("unknown workflow `{unknown}`"),
+            ));
+        }
+        Ok(System { workflows, pieces })
+    }
+
+    fn part_1(&self, system: &Self::Input) -> Answer {
+        let System { workflows, pieces } = system;
+
+        pieces
+            .iter()
+            .map(|piece| {
+                let mut next_workflow = "in";
+                while next_workflow != "A" && next_workflow != "R" {
+                    for rule in workflows.get(next_workflow).unwrap().rules.iter() {
+                        if rule.is_valid(piece) {
+                            next_workflow = rule.fallback.as_str();
+                            break;
+                        }
+                    }
+                }
+                if next_workflow == "A" {
+                    piece.sum()
+                } else {
+                    0
+                }
+            })
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part_2(&self, system: &Self::Input) -> Answer {
+        let workflows = &system.workflows;
+        let mut queue = Vec::new();
+        queue.push(PieceTracker {
+            piece: PiecesRange::new(),
+            workflow: "in".to_string(),
+            rule_to_apply: 0,
+        });
+
+        let mut possibilities = 0;
+        while let Some(piece) = queue.pop() {
+            let rule = workflows
+                .get(piece.workflow.as_str())
+                .unwrap()
+                .rules
+                .get(piece.rule_to_apply)
+                .unwrap();
+            if let Some(new_piece) = rule.apply_to_range(&piece.piece) {
+                if rule.fallback.clone() == "A" {
+                    possibilities += new_piece.posibilities();
+                } else if rule.fallback.clone() != "R" {
+                    queue.push(PieceTracker {
+                        piece: new_piece,
+                        workflow: rule.fallback.clone(),
+                        rule_to_apply: 0,
+                    });
+                }
+            }
+            if let Some(new_piece) = rule.apply_inverse_to_range(&piece.piece) {
+                queue.push(PieceTracker {
+                    piece: new_piece,
+                    workflow: piece.workflow,
+                    rule_to_apply: piece.rule_to_apply + 1,
+                });
+            }
+        }
+        possibilities.into()
+    }
+}
+
+/// Workflows by name and the pieces to sort.
+pub struct System {
+    pub workflows: HashMap<String, Workflow>,
+    pub pieces: Vec<Piece>,
+}
+
+struct PieceTracker {
+    piece: PiecesRange,
+    workflow: String,
+    rule_to_apply: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Condition {
+    Inferior,
+    Greater,
+    None,
+}
+/// Sends a piece to `fallback` when its `category` rating meets the condition.
+pub struct Rule {
+    pub category: char,
+    pub condition: Condition,
+    pub value: u64,
+    pub fallback: String,
+}
+impl Rule {
+    /// Parses the comma separated rules of a workflow.
+    pub fn parse_rules(s: &str) -> Result<Vec<Self>, ParseError> {
+        let re_rule = Regex::new(r"^(?:([xmas])([<>])(\d+):)?([a-zA-Z]+)$").unwrap();
+        s.split(',')
+            .map(|rule| {
+                let c = re_rule
+                    .captures(rule)
+                    .ok_or_else(|| ParseError::at(s, rule, format!("invalid rule `{rule}`")))?;
+                let category = c.get(1).map_or('0', |f| f.as_str().chars().next().unwrap());
+                let condition = match c.get(2).map(|f| f.as_str()) {
+                    Some("<") => Condition::Inferior,
+                    Some(_) => Condition::Greater,
+                    None => Condition::None,
+                };
+                let value = match c.get(3) {
+                    Some(value) => parse_number(s, value.as_str())?,
+                    None => 0,
+                };
+                let fallback = c[4].to_string();
+                Ok(Self {
+                    category,
+                    condition,
+                    value,
+                    fallback,
+                })
+            })
+            .collect()
+    }
+
+    pub fn is_valid(&self, piece: &Piece) -> bool {
+        let value = match self.category {
+            'x' => piece.x,
+            'm' => piece.m,
+            'a' => piece.a,
+            's' => piece.s,
+            _ => 0,
+        };
+        match self.condition {
+            Condition::Inferior => value < self.value,
+            Condition::Greater => value > self.value,
+            Condition::None => true,
+        }
+    }
+
+    /// Part of the range accepted by the rule, if any.
+    pub fn apply_to_range(&self, piece: &PiecesRange) -> Option<PiecesRange> {
+        let mut new_piece = piece.clone();
+        match self.category {
+            'x' => match self.condition {
+                Condition::Greater => match new_piece.x.end().cmp(&self.value) {
+                    Ordering::Greater => {
+                        new_piece.x =
+                            max(*new_piece.x.start(), self.value + 1)..=*new_piece.x.end();
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::Inferior => match new_piece.x.start().cmp(&self.value) {
+                    Ordering::Less => {
+                        new_piece.x =
+                            *new_piece.x.start()..=min(*new_piece.x.end(), self.value - 1);
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::None => Some(piece.clone()),
+            },
+            'm' => match self.condition {
+                Condition::Greater => match new_piece.m.end().cmp(&self.value) {
+                    Ordering::Greater => {
+                        new_piece.m =
+                            max(*new_piece.m.start(), self.value + 1)..=*new_piece.m.end();
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::Inferior => match new_piece.m.start().cmp(&self.value) {
+                    Ordering::Less => {
+                        new_piece.m =
+                            *new_piece.m.start()..=min(*new_piece.m.end(), self.value - 1);
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::None => Some(piece.clone()),
+            },
+            'a' => match self.condition {
+                Condition::Greater => match new_piece.a.end().cmp(&self.value) {
+                    Ordering::Greater => {
+                        new_piece.a =
+                            max(*new_piece.a.start(), self.value + 1)..=*new_piece.a.end();
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::Inferior => match new_piece.a.start().cmp(&self.value) {
+                    Ordering::Less => {
+                        new_piece.a =
+                            *new_piece.a.start()..=min(*new_piece.a.end(), self.value - 1);
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::None => Some(piece.clone()),
+            },
+            's' => match self.condition {
+                Condition::Greater => match new_piece.s.end().cmp(&self.value) {
+                    Ordering::Greater => {
+                        new_piece.s =
+                            max(*new_piece.s.start(), self.value + 1)..=*new_piece.s.end();
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::Inferior => match new_piece.s.start().cmp(&self.value) {
+                    Ordering::Less => {
+                        new_piece.s =
+                            *new_piece.s.start()..=min(*new_piece.s.end(), self.value - 1);
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::None => Some(piece.clone()),
+            },
+            _ => Some(piece.clone()),
+        }
+    }
+
+    /// Part of the range rejected by the rule, if any.
+    pub fn apply_inverse_to_range(&self, piece: &PiecesRange) -> Option<PiecesRange> {
+        let mut new_piece = piece.clone();
+        match self.category {
+            'x' => match self.condition {
+                Condition::Greater => match new_piece.x.start().cmp(&self.value) {
+                    Ordering::Less | Ordering::Equal => {
+                        new_piece.x = *new_piece.x.start()..=min(*new_piece.x.end(), self.value);
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::Inferior => match new_piece.x.end().cmp(&self.value) {
+                    Ordering::Greater | Ordering::Equal => {
+                        new_piece.x = max(*new_piece.x.start(), self.value)..=*new_piece.x.end();
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::None => None,
+            },
+            'm' => match self.condition {
+                Condition::Greater => match new_piece.m.start().cmp(&self.value) {
+                    Ordering::Less | Ordering::Equal => {
+                        new_piece.m = *new_piece.m.start()..=min(*new_piece.m.end(), self.value);
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::Inferior => match new_piece.m.end().cmp(&self.value) {
+                    Ordering::Greater | Ordering::Equal => {
+                        new_piece.m = max(*new_piece.m.start(), self.value)..=*new_piece.m.end();
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::None => None,
+            },
+            'a' => match self.condition {
+                Condition::Greater => match new_piece.a.start().cmp(&self.value) {
+                    Ordering::Less | Ordering::Equal => {
+                        new_piece.a = *new_piece.a.start()..=min(*new_piece.a.end(), self.value);
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::Inferior => match new_piece.a.end().cmp(&self.value) {
+                    Ordering::Greater | Ordering::Equal => {
+                        new_piece.a = max(*new_piece.a.start(), self.value)..=*new_piece.a.end();
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::None => None,
+            },
+            's' => match self.condition {
+                Condition::Greater => match new_piece.s.start().cmp(&self.value) {
+                    Ordering::Less | Ordering::Equal => {
+                        new_piece.s = *new_piece.s.start()..=min(*new_piece.s.end(), self.value);
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::Inferior => match new_piece.s.end().cmp(&self.value) {
+                    Ordering::Greater | Ordering::Equal => {
+                        new_piece.s = max(*new_piece.s.start(), self.value)..=*new_piece.s.end();
+                        Some(new_piece)
+                    }
+                    _ => None,
+                },
+                Condition::None => None,
+            },
+            _ => None,
+        }
+    }
+}
+pub struct Workflow {
+    pub rules: Vec<Rule>,
+}
+pub struct Piece {
+    pub x: u64,
+    pub m: u64,
+    pub a: u64,
+    pub s: u64,
+}
+impl Piece {
+    pub fn new(x: u64, m: u64, a: u64, s: u64) -> Self {
+        Self { x, m, a, s }
+    }
+    pub fn sum(&self) -> u64 {
+        self.x + self.m + self.a + self.s
+    }
+}
+/// Ratings ranges of a group of pieces.
+#[derive(Debug, Clone)]
+pub struct PiecesRange {
+    pub x: RangeInclusive<u64>,
+    pub m: RangeInclusive<u64>,
+    pub a: RangeInclusive<u64>,
+    pub s: RangeInclusive<u64>,
+}
+impl Default for PiecesRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PiecesRange {
+    /// Every possible piece, with ratings from 1 to 4000.
+    pub fn new() -> Self {
+        let default = 1..=4000;
+        Self {
+            x: default.clone(),
+            m: default.clone(),
+            a: default.clone(),
+            s: default.clone(),
+        }
+    }
+    pub fn posibilities(&self) -> u64 {
+        (self.x.end() - self.x.start() + 1)
+            * (self.m.end() - self.m.start() + 1)
+            * (self.a.end() - self.a.start() + 1)
+            * (self.s.end() - self.s.start() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests_day19 {
+    use crate::*;
+    use common::{check_property, Rng};
+
+    #[test]
+    fn test_rule_parse() {
+        let rules = Rule::parse_rules("a<2006:qkq,m>2090:A,rfg").unwrap();
+        assert_eq!(rules.len(), 3);
+        let rule = rules.first().unwrap();
+        assert_eq!(rule.fallback, "qkq".to_string());
+        assert_eq!(rule.category, 'a');
+        assert_eq!(rule.value, 2006);
+        assert_eq!(rule.condition, Condition::Inferior);
+    }
+    #[test]
+    fn test_parse_error() {
+        let input = "in{s<1351:px,qqz}\npx{a<2006:A,m>20x0:R,A}\n\n{x=787,m=2655,a=1222,s=2876}";
+        let error = Day19.parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 13: invalid rule `m>20x0:R`"
+        );
+        let input = "in{s<1351:px,qqz}\npx{a<2006:A,R}\n\n{x=787,m=2655,a=1222,s=2876}";
+        let error = Day19.parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 14: unknown workflow `qqz`"
+        );
+        let input = "in{A}\n\n{x=1,m=2,a=3,s=4}\n\n{x=1,m=2,a=3,s=4}";
+        let error = Day19.parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 1: expected workflows then pieces"
+        );
+    }
+    #[test]
+    fn test_rule_range() {
+        let rule = Rule {
+            category: 'x',
+            condition: Condition::Greater,
+            value: 2000,
+            fallback: "qkq".to_string(),
+        };
+        let piece = PiecesRange::new();
+
+        let new_piece = rule.apply_to_range(&piece).unwrap();
+        assert_eq!(new_piece.x, 2001..=4000);
+        let inv_new_piece = rule.apply_inverse_to_range(&piece).unwrap();
+        assert_eq!(inv_new_piece.x, 1..=2000);
+        assert!(rule.apply_inverse_to_range(&new_piece).is_none());
+    }
+
+    fn random_range(rng: &mut Rng) -> RangeInclusive<u64> {
+        let start = rng.range(1..=10) as u64;
+        start..=start + rng.range(0..=4) as u64
+    }
+
+    fn contains(range: &PiecesRange, piece: &Piece) -> bool {
+        range.x.contains(&piece.x)
+            && range.m.contains(&piece.m)
+            && range.a.contains(&piece.a)
+            && range.s.contains(&piece.s)
+    }
+
+    #[test]
+    fn test_rule_range_partition() {
+        check_property(500, |rng| {
+            let condition = match rng.below(3) {
+                0 => Condition::Inferior,
+                1 => Condition::Greater,
+                _ => Condition::None,
+            };
+            let rule = Rule {
+                category: *rng.choose(&['x', 'm', 'a', 's']),
+                condition,
+                value: rng.range(1..=15) as u64,
+                fallback: "A".to_string(),
+            };
+            let range = PiecesRange {
+                x: random_range(rng),
+                m: random_range(rng),
+                a: random_range(rng),
+                s: random_range(rng),
+            };
+            let accepted = rule.apply_to_range(&range);
+            let rejected = rule.apply_inverse_to_range(&range);
+            assert_eq!(
+                accepted
+                    .iter()
+                    .chain(rejected.iter())
+                    .map(|r| r.posibilities())
+                    .sum::<u64>(),
+                range.posibilities()
+            );
+
+            for x in range.x.clone() {
+                for m in range.m.clone() {
+                    for a in range.a.clone() {
+                        for s in range.s.clone() {
+                            let piece = Piece::new(x, m, a, s);
+                            let in_accepted =
+                                accepted.as_ref().is_some_and(|r| contains(r, &piece));
+                            let in_rejected =
+                                rejected.as_ref().is_some_and(|r| contains(r, &piece));
+                            assert_eq!(in_accepted, rule.is_valid(&piece));
+                            assert_eq!(in_rejected, !rule.is_valid(&piece));
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
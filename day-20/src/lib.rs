@@ -0,0 +1,351 @@
+use common::{Answer, ParseError, Solution};
+use num_integer::lcm;
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<ModuleConfig>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(r"^([%&])?([a-z]+) -> ([a-z]+(?:, [a-z]+)*)$").unwrap();
+        let mut inputs = input
+            .lines()
+            .map(|line| {
+                let cap = re.captures(line).ok_or_else(|| {
+                    ParseError::at(input, line, "expected `[%|&]<name> -> <outputs>`")
+                })?;
+                Ok(ModuleConfig {
+                    type_char: cap.get(1).and_then(|c| c.as_str().chars().next()),
+                    name: cap[2].to_string(),
+                    outputs: cap[3].split(", ").map(String::from).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        if !inputs.iter().any(|m| m.name == "broadcaster") {
+            return Err(ParseError::new(1, 1, "expected a `broadcaster` module"));
+        }
+        //Add inputs with no outputs
+        let mut inputs_name = inputs.iter().map(|m| m.name.clone()).collect::<Vec<_>>();
+        let mut inputs_with_no_outputs: Vec<_> = Vec::new();
+        for module in inputs.iter() {
+            for r in module.outputs.iter() {
+                if !inputs_name.contains(r) {
+                    inputs_name.push(r.clone());
+                    inputs_with_no_outputs.push(ModuleConfig {
+                        type_char: None,
+                        name: r.clone(),
+                        outputs: vec![],
+                    });
+                }
+            }
+        }
+        inputs.extend(inputs_with_no_outputs);
+        Ok(inputs)
+    }
+
+    fn part_1(&self, inputs: &Self::Input) -> Answer {
+        let modules = build_modules(inputs);
+
+        let mut signals: VecDeque<Signal> = VecDeque::new();
+        let mut signals_sum: (u64, u64) = (0, 0);
+        for _ in 0..1000 {
+            signals.push_back(Signal {
+                from: Rc::clone(&modules["broadcaster"]),
+                to: Rc::clone(&modules["broadcaster"]),
+                value: false,
+            });
+            while let Some(signal) = signals.pop_front() {
+                if !signal.value {
+                    signals_sum.0 += 1;
+                } else {
+                    signals_sum.1 += 1;
+                }
+                let new_signals = Module::handle_signal(signal);
+                signals.extend(new_signals);
+            }
+        }
+        (signals_sum.0 * signals_sum.1).into()
+    }
+
+    fn part_2(&self, inputs: &Self::Input) -> Answer {
+        let modules = build_modules(inputs);
+
+        let mut signals: VecDeque<Signal> = VecDeque::new();
+        let mut push_counter = 0;
+        let mut cycle_counter: [u64; 4] = [0; 4];
+        //rx is wired to only four conjunctions modules with inverters so we can find the cycle by finding the lcm of the cycle of each conjunction (jj, gf, xz and bz)
+        'outer: loop {
+            push_counter += 1;
+            signals.push_back(Signal {
+                from: Rc::clone(&modules["broadcaster"]),
+                to: Rc::clone(&modules["broadcaster"]),
+                value: false,
+            });
+            while let Some(signal) = signals.pop_front() {
+                if Module::get_name(&signal.from) == "jj" && !signal.value {
+                    cycle_counter[0] = push_counter;
+                } else if Module::get_name(&signal.from) == "gf" && !signal.value {
+                    cycle_counter[1] = push_counter;
+                } else if Module::get_name(&signal.from) == "xz" && !signal.value {
+                    cycle_counter[2] = push_counter;
+                } else if Module::get_name(&signal.from) == "bz" && !signal.value {
+                    cycle_counter[3] = push_counter;
+                }
+                if cycle_counter.iter().all(|f| *f > 0) {
+                    break 'outer;
+                }
+                let new_signals = Module::handle_signal(signal);
+                signals.extend(new_signals);
+            }
+        }
+        lcm(
+            lcm(cycle_counter[0], cycle_counter[1]),
+            lcm(cycle_counter[2], cycle_counter[3]),
+        )
+        .into()
+    }
+}
+
+/// A module line of the configuration: its type (`%`, `&` or none), name and outputs.
+pub struct ModuleConfig {
+    pub type_char: Option<char>,
+    pub name: String,
+    pub outputs: Vec<String>,
+}
+
+/// Wires the configured modules together, indexed by name.
+pub fn build_modules(inputs: &[ModuleConfig]) -> HashMap<&str, Rc<RefCell<Module<'_>>>> {
+    //Replace all references by name to references by index in the inputs array
+    let inputs_with_index = inputs
+        .iter()
+        .map(|module| {
+            (
+                module.type_char,
+                module.name.as_str(),
+                module
+                    .outputs
+                    .iter()
+                    .map(|r| inputs.iter().position(|m| &m.name == r).unwrap() as u64)
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut modules: HashMap<&str, Rc<RefCell<Module>>> = HashMap::new();
+    for (type_char, name, _) in inputs_with_index.iter() {
+        modules.insert(
+            name,
+            if let Some(type_char) = type_char {
+                match type_char {
+                    '&' => Rc::new(RefCell::new(Module::Conjunction(Rc::new(RefCell::new(
+                        Conjunction {
+                            name,
+                            inputs: vec![],
+                            last_pulse: vec![],
+                            outputs: vec![],
+                        },
+                    ))))),
+                    '%' => Rc::new(RefCell::new(Module::FlipFlop(Rc::new(RefCell::new(
+                        FlipFlop {
+                            name,
+                            outputs: vec![],
+                            state: false,
+                        },
+                    ))))),
+                    _ => panic!("Unknown type char"),
+                }
+            } else {
+                Rc::new(RefCell::new(Module::Broadcast(Rc::new(RefCell::new(
+                    Broadcast {
+                        name,
+                        outputs: vec![],
+                    },
+                )))))
+            },
+        );
+    }
+    //Create ref between modules
+    for (_, name, outputs_index) in inputs_with_index.iter() {
+        let ouputs = outputs_index
+            .iter()
+            .map(|index| Rc::clone(&modules[inputs_with_index[*index as usize].1]))
+            .collect::<Vec<_>>();
+        Module::add_outputs(&modules[*name], ouputs);
+    }
+    modules
+}
+
+#[derive(PartialEq, Clone)]
+pub struct FlipFlop<'a> {
+    name: &'a str,
+    outputs: Vec<Rc<RefCell<Module<'a>>>>,
+    state: bool,
+}
+
+#[derive(PartialEq, Clone)]
+pub struct Conjunction<'a> {
+    name: &'a str,
+    inputs: Vec<Rc<RefCell<Module<'a>>>>,
+    last_pulse: Vec<bool>,
+    outputs: Vec<Rc<RefCell<Module<'a>>>>,
+}
+
+#[derive(PartialEq, Clone)]
+pub struct Broadcast<'a> {
+    name: &'a str,
+    outputs: Vec<Rc<RefCell<Module<'a>>>>,
+}
+
+/// Pulse sent between two modules, `value` is true for a high pulse.
+pub struct Signal<'a> {
+    pub from: Rc<RefCell<Module<'a>>>,
+    pub to: Rc<RefCell<Module<'a>>>,
+    pub value: bool,
+}
+
+#[derive(PartialEq, Clone)]
+pub enum Module<'a> {
+    Broadcast(Rc<RefCell<Broadcast<'a>>>),
+    Conjunction(Rc<RefCell<Conjunction<'a>>>),
+    FlipFlop(Rc<RefCell<FlipFlop<'a>>>),
+}
+
+impl<'a> Module<'a> {
+    /// Signals emitted by the receiving module.
+    pub fn handle_signal(signal: Signal<'a>) -> Vec<Signal<'a>> {
+        let self_ = &signal.to;
+        match &*self_.borrow() {
+            Module::Broadcast(broadcast) => {
+                let broadcast = broadcast.borrow();
+                broadcast
+                    .outputs
+                    .iter()
+                    .map(|output| Signal {
+                        from: Rc::clone(self_),
+                        to: Rc::clone(output),
+                        value: signal.value,
+                    })
+                    .collect()
+            }
+            Module::Conjunction(conjunction) => {
+                {
+                    let index_of_input_signal = conjunction
+                        .borrow()
+                        .inputs
+                        .iter()
+                        .position(|input| Module::names_eq(input, &signal.from))
+                        .unwrap();
+                    let mut conjunction = conjunction.borrow_mut();
+                    if conjunction.inputs.len() != conjunction.last_pulse.len() {
+                        conjunction.last_pulse = vec![false; conjunction.inputs.len()];
+                    }
+                    conjunction.last_pulse[index_of_input_signal] = signal.value;
+                }
+
+                conjunction
+                    .borrow()
+                    .outputs
+                    .iter()
+                    .map(|output| Signal {
+                        from: Rc::clone(self_),
+                        to: Rc::clone(output),
+                        value: !conjunction.borrow().last_pulse.iter().all(|pulse| *pulse),
+                    })
+                    .collect()
+            }
+            Module::FlipFlop(flip_flop) => {
+                if !signal.value {
+                    {
+                        let mut flip_flop = flip_flop.borrow_mut();
+                        flip_flop.state = !flip_flop.state;
+                    }
+
+                    flip_flop
+                        .borrow()
+                        .outputs
+                        .iter()
+                        .map(|output| Signal {
+                            from: Rc::clone(self_),
+                            to: Rc::clone(output),
+                            value: flip_flop.borrow().state,
+                        })
+                        .collect()
+                } else {
+                    vec![]
+                }
+            }
+        }
+    }
+    pub fn names_eq(self_: &Rc<RefCell<Module<'a>>>, other: &Rc<RefCell<Module<'a>>>) -> bool {
+        match (&*self_.borrow(), &*other.borrow()) {
+            (Module::Broadcast(broadcast), Module::Broadcast(other_broadcast)) => {
+                broadcast.borrow().name == other_broadcast.borrow().name
+            }
+            (Module::Conjunction(conjunction), Module::Conjunction(other_conjunction)) => {
+                conjunction.borrow().name == other_conjunction.borrow().name
+            }
+            (Module::FlipFlop(flip_flop), Module::FlipFlop(other_flip_flop)) => {
+                flip_flop.borrow().name == other_flip_flop.borrow().name
+            }
+            _ => false,
+        }
+    }
+    pub fn get_name(self_: &Rc<RefCell<Module<'a>>>) -> &'a str {
+        match &*self_.borrow() {
+            Module::Broadcast(broadcast) => broadcast.borrow().name,
+            Module::Conjunction(conjunction) => conjunction.borrow().name,
+            Module::FlipFlop(flip_flop) => flip_flop.borrow().name,
+        }
+    }
+    pub fn add_input(self_: &Rc<RefCell<Module<'a>>>, input: &Rc<RefCell<Module<'a>>>) {
+        if let Module::Conjunction(conjunction) = &*self_.borrow() {
+            let mut conjunction = conjunction.borrow_mut();
+            conjunction.inputs.push(Rc::clone(input));
+            conjunction.last_pulse.push(false);
+        }
+    }
+    pub fn add_outputs(self_: &Rc<RefCell<Module<'a>>>, outputs: Vec<Rc<RefCell<Module<'a>>>>) {
+        match &*self_.borrow() {
+            Module::Broadcast(broadcast) => {
+                let mut broadcast = broadcast.borrow_mut();
+                for output in outputs.iter() {
+                    Module::add_input(output, self_);
+                }
+                broadcast.outputs = outputs;
+            }
+            Module::Conjunction(conjunction) => {
+                let mut conjunction = conjunction.borrow_mut();
+                for output in outputs.iter() {
+                    Module::add_input(output, self_);
+                }
+                conjunction.outputs = outputs;
+            }
+            Module::FlipFlop(flip_flop) => {
+                let mut flip_flop = flip_flop.borrow_mut();
+                for output in outputs.iter() {
+                    Module::add_input(output, self_);
+                }
+                flip_flop.outputs = outputs;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_day20 {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "broadcaster -> a\n%a -> inv, con\n&inv => b";
+        let error = Day20.parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected `[%|&]<name> -> <outputs>`"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use common::{Answer, Grid, ParseError, Point2, Solution};
+use polyfit_rs::polyfit_rs::polyfit;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+pub struct Day21 {
+    /// Number of steps taken in part 1.
+    pub part_1_steps: u64,
+    /// Number of steps taken in part 2, on the infinitely repeated map.
+    pub part_2_steps: u64,
+    /// Number of map widths walked before sampling the growth of reachable plots.
+    pub offset: u64,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Self {
+            part_1_steps: 64,
+            part_2_steps: 26501365,
+            offset: 0,
+        }
+    }
+}
+
+impl Solution for Day21 {
+    type Input = Grid<char>;
+
+    /// The map must be a square with the start `S` at its center.
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(input, |c| matches!(c, '.' | '#' | 'S').then_some(c))?;
+        if map.height() != map.width() {
+            return Err(ParseError::new(
+                map.height(),
+                1,
+                format!(
+                    "expected a square map, found {} rows of {} tiles",
+                    map.height(),
+                    map.width()
+                ),
+            ));
+        }
+        let center = (map.height() - 1) / 2;
+        if map[(center, center)] != 'S' {
+            return Err(ParseError::new(
+                center + 1,
+                center + 1,
+                "expected the start `S` at the center of the map",
+            ));
+        }
+        Ok(map)
+    }
+
+    fn part_1(&self, map: &Self::Input) -> Answer {
+        count_plots(map, self.part_1_steps).into()
+    }
+
+    fn part_2(&self, map: &Self::Input) -> Answer {
+        count_plots_extrapolated(map, self.part_2_steps, self.offset).into()
+    }
+}
+
+/// Garden plots reachable in exactly `step_count` steps, the map repeating infinitely.
+pub fn count_plots(map: &Grid<char>, step_count: u64) -> u64 {
+    let line_width = map.width() as i64;
+    let start_pos = Point2::new((line_width - 1) / 2, (line_width - 1) / 2);
+    assert_eq!(*map.get_wrapping(start_pos.x, start_pos.y), 'S');
+
+    let mut states: Vec<State> = vec![State {
+        position: start_pos,
+        step: 0,
+    }];
+    for _step in 0..step_count {
+        states = next_states(map, &states);
+    }
+    assert!(states.iter().all(|s| s.step == step_count));
+    states.len() as u64
+}
+
+/// Plots reachable with one more step from `states`, the map repeating infinitely.
+fn next_states(map: &Grid<char>, states: &[State]) -> Vec<State> {
+    let mut next_states: Vec<State> = vec![];
+    for state in states.iter() {
+        for position in state.position.neighbours() {
+            if *map.get_wrapping(position.x, position.y) != '#'
+                && !next_states.iter().any(|s| s.position == position)
+            {
+                next_states.push(State {
+                    position,
+                    step: state.step + 1,
+                });
+            }
+        }
+    }
+    next_states
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    position: Point2<i64>,
+    step: u64,
+}
+
+/// Same as [`count_plots`], fitting a quadratic on the growth each time a map width is walked.
+pub fn count_plots_extrapolated(map: &Grid<char>, step_count: u64, offset: u64) -> u64 {
+    let map_size = map.width() as u64;
+
+    if step_count < (2 + offset) * map_size {
+        return count_plots(map, step_count);
+    }
+
+    let remainder = step_count % map_size;
+    let xs: Vec<f64> = vec![offset as f64, (offset + 1) as f64, (offset + 2) as f64];
+    let x = (step_count / map_size) as i64;
+    let step_to_take: Vec<u64> = xs
+        .iter()
+        .map(|x| map_size * (*x as u64) + remainder)
+        .collect();
+    let mut ys: Vec<f64> = vec![];
+
+    let line_width = map.width() as i64;
+    let start_pos = Point2::new((line_width - 1) / 2, (line_width - 1) / 2);
+    assert_eq!(*map.get_wrapping(start_pos.x, start_pos.y), 'S');
+
+    let mut states: Vec<State> = vec![State {
+        position: start_pos,
+        step: 0,
+    }];
+    for step in 0..=*step_to_take.iter().max().unwrap() {
+        if step > 0 {
+            states = next_states(map, &states);
+        }
+
+        if step_to_take.contains(&(step)) {
+            ys.push(states.len() as f64);
+        }
+    }
+    assert_eq!(ys.len(), 3);
+
+    let coeff = polyfit(&xs, &ys, 2).unwrap();
+    let coeff = coeff.iter().map(|x| x.round() as i64).collect::<Vec<i64>>();
+    (x.pow(2) * coeff[2] + x * coeff[1] + coeff[0]) as u64
+}
+
+#[cfg(test)]
+mod tests_day21 {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day21::default().parse("...\nS..\n...").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected the start `S` at the center of the map"
+        );
+    }
+
+    #[test]
+    fn test_reference_part_2() {
+        let input = include_str!("../../aoc-2023-inputs/day-21/test.txt");
+        let map = Day21::default().parse(input).unwrap();
+        assert_eq!(reference::part_2(&map, 6), 16);
+        assert_eq!(reference::part_2(&map, 10), 50);
+        assert_eq!(reference::part_2(&map, 50), 1594);
+        assert_eq!(reference::part_2(&map, 500), 167004);
+    }
+}
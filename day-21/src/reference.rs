@@ -0,0 +1,31 @@
+//! Slow but straightforward solution, to check [`Day21`](crate::Day21) against.
+
+use common::{Grid, Point2};
+use std::collections::{HashMap, VecDeque};
+
+/// Garden plots reachable in exactly `step_count` steps, the map repeating infinitely.
+///
+/// A plot is reachable when its distance to the start is at most `step_count` and of the same
+/// parity, as the walk can then go back and forth until the steps run out.
+pub fn part_2(map: &Grid<char>, step_count: u64) -> u64 {
+    let center = (map.width() as i64 - 1) / 2;
+    let start = Point2::new(center, center);
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(position) = queue.pop_front() {
+        let distance = distances[&position];
+        if distance == step_count {
+            continue;
+        }
+        for next in position.neighbours() {
+            if *map.get_wrapping(next.x, next.y) != '#' && !distances.contains_key(&next) {
+                distances.insert(next, distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+        .values()
+        .filter(|distance| *distance % 2 == step_count % 2)
+        .count() as u64
+}
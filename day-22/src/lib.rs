@@ -0,0 +1,246 @@
+use std::cmp::min;
+
+use common::{parse_number, Answer, ParseError, Solution};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_bricks(input)
+    }
+
+    fn part_1(&self, bricks: &Self::Input) -> Answer {
+        let mut bricks = bricks.clone();
+        apply_gravity_bricks(&mut bricks);
+        find_safe_bricks(&bricks).len().into()
+    }
+
+    fn part_2(&self, bricks: &Self::Input) -> Answer {
+        let mut bricks = bricks.clone();
+        apply_gravity_bricks(&mut bricks);
+        let safe_bricks = find_safe_bricks(&bricks);
+
+        let non_safe_bricks = bricks
+            .iter()
+            .filter(|x| !safe_bricks.contains(x))
+            .collect::<Vec<_>>();
+        non_safe_bricks
+            .iter()
+            .map(|brick_to_remove| {
+                let mut bricks = bricks.clone();
+                bricks.retain(|x| x.id != brick_to_remove.id);
+                apply_gravity_bricks(&mut bricks)
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+/// Parses the snapshot, bricks sorted from the lowest.
+pub fn parse_bricks(input: &str) -> Result<Vec<Brick>, ParseError> {
+    let mut bricks = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let parse_position = |s: &str| {
+            let coords = s
+                .split(',')
+                .map(|x| parse_number(input, x))
+                .collect::<Result<Vec<usize>, ParseError>>()?;
+            match coords[..] {
+                [x, y, z] if z > 0 => Ok(Position { x, y, z }),
+                [_, _, _] => Err(ParseError::at(input, s, "bricks must be above the ground")),
+                _ => Err(ParseError::at(input, s, "expected `<x>,<y>,<z>`")),
+            }
+        };
+        let (start, end) = line
+            .split_once('~')
+            .ok_or_else(|| ParseError::at(input, line, "expected `<start>~<end>`"))?;
+        let (start, end) = (parse_position(start)?, parse_position(end)?);
+        let differences = [start.x != end.x, start.y != end.y, start.z != end.z];
+        if differences.into_iter().filter(|d| *d).count() > 1 {
+            return Err(ParseError::at(
+                input,
+                line,
+                "bricks must be aligned on one axis",
+            ));
+        }
+        if start.x > end.x || start.y > end.y || start.z > end.z {
+            return Err(ParseError::at(
+                input,
+                line,
+                "the start must be before the end",
+            ));
+        }
+        bricks.push(Brick::new(i, start, end));
+    }
+    bricks.sort_by_key(|a| a.get_lowest_z());
+    Ok(bricks)
+}
+
+/// Lets every brick fall and returns how many of them moved.
+pub fn apply_gravity_bricks(bricks: &mut [Brick]) -> usize {
+    let mut moved_bricks = 0;
+    for brick_id in 0..bricks.len() {
+        let mut has_moved = false;
+        loop {
+            if bricks[brick_id].get_lowest_z() == 1 {
+                break;
+            }
+
+            bricks[brick_id].move_down();
+            if bricks.iter().any(|other| {
+                if other.id == bricks[brick_id].id {
+                    false
+                } else {
+                    bricks[brick_id].is_colliding(other)
+                }
+            }) {
+                bricks[brick_id].move_up();
+                break;
+            } else {
+                has_moved = true;
+            }
+        }
+        if has_moved {
+            moved_bricks += 1;
+        }
+    }
+    moved_bricks
+}
+
+/// Bricks that can be disintegrated without any other brick falling.
+pub fn find_safe_bricks(bricks: &[Brick]) -> Vec<&Brick> {
+    bricks
+        .iter()
+        .filter(|brick| {
+            let mut b = **brick;
+            b.move_up();
+
+            let bricks_above = bricks
+                .iter()
+                .filter(|x| {
+                    if x.id == b.id {
+                        false
+                    } else {
+                        b.is_colliding(x)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            bricks_above
+                .iter()
+                .map(|brick| {
+                    let mut b: Brick = **brick;
+                    b.move_down();
+                    let mut brick_support = bricks
+                        .iter()
+                        .map(|x| if b.is_colliding(x) { 1 } else { 0 })
+                        .sum::<usize>();
+                    if b.axis == Axis::Z {
+                        brick_support -= 1;
+                    }
+                    brick_support
+                })
+                .all(|x| x > 1)
+        })
+        .collect::<Vec<_>>()
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct Brick {
+    id: usize,
+    axis: Axis,
+    start: Position,
+    end: Position,
+}
+impl Brick {
+    pub fn new(id: usize, start: Position, end: Position) -> Self {
+        let axis = if start.x != end.x {
+            Axis::X
+        } else if start.y != end.y {
+            Axis::Y
+        } else {
+            Axis::Z
+        };
+        Self {
+            id,
+            axis,
+            start,
+            end,
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+    pub fn start(&self) -> Position {
+        self.start
+    }
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    pub fn get_lowest_z(&self) -> usize {
+        min(self.start.z, self.end.z)
+    }
+
+    pub fn move_down(&mut self) {
+        if !(self.start.z > 1 && self.end.z > 1) {
+            return;
+        }
+
+        self.start.z -= 1;
+        self.end.z -= 1;
+    }
+
+    pub fn move_up(&mut self) {
+        self.start.z += 1;
+        self.end.z += 1;
+    }
+
+    pub fn is_colliding(&self, other: &Brick) -> bool {
+        (self.start.x <= other.end.x && self.end.x >= other.start.x)
+            && (self.start.y <= other.end.y && self.end.y >= other.start.y)
+            && (self.start.z <= other.end.z && self.end.z >= other.start.z)
+    }
+}
+impl PartialEq for Brick {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[cfg(test)]
+mod tests_day22 {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_bricks("1,0,1~1,2,1\n0,0,2~2,2,2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: bricks must be aligned on one axis"
+        );
+        let error = parse_bricks("1,0,1~1,2,1\n0,0,2~2,0").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected `<x>,<y>,<z>`"
+        );
+    }
+}
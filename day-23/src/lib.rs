@@ -0,0 +1,424 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use common::{Answer, Direction, Grid, ParseError, Point2, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grid<char>;
+
+    /// The hike starts on the second tile of the top row.
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input, |c| {
+            matches!(c, '#' | '.' | '^' | '>' | 'v' | '<').then_some(c)
+        })?;
+        if grid.get(1, 0) != Some(&'.') {
+            return Err(ParseError::new(1, 2, "expected the start of the hike `.`"));
+        }
+        Ok(grid)
+    }
+
+    fn part_1(&self, grid: &Self::Input) -> Answer {
+        let map = Map::from_chars(grid);
+        map.find_longest_hike().into()
+    }
+
+    fn part_2(&self, grid: &Self::Input) -> Answer {
+        let graph_map = GraphMap::new(grid);
+        graph_map.find_longest_hike().into()
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub enum TileType {
+    Wall,
+    Slope,
+    Empty,
+}
+pub struct Tile {
+    tile_type: TileType,
+    direction: Option<Direction>,
+}
+impl Tile {
+    pub fn from_char(c: char) -> Self {
+        Self {
+            tile_type: match c {
+                '#' => TileType::Wall,
+                '.' => TileType::Empty,
+                _ => TileType::Slope,
+            },
+            direction: match c {
+                '^' => Some(Direction::North),
+                '>' => Some(Direction::East),
+                'v' => Some(Direction::South),
+                '<' => Some(Direction::West),
+                _ => None,
+            },
+        }
+    }
+    pub fn from_char_without_slope(c: char) -> Self {
+        Self {
+            tile_type: match c {
+                '#' => TileType::Wall,
+                _ => TileType::Empty,
+            },
+            direction: None,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone)]
+pub struct State {
+    position: Point2<u16>,
+    already_visited: Vec<Point2<u16>>,
+    distance: u16,
+}
+impl State {
+    pub fn new(position: Point2<u16>, distance: u16) -> Self {
+        Self {
+            position,
+            distance,
+            already_visited: vec![],
+        }
+    }
+
+    pub fn new_from_state(position: Point2<u16>, last_state: &State) -> Self {
+        let mut already_visited = last_state.already_visited.clone();
+        already_visited.push(last_state.position);
+        Self {
+            position,
+            distance: last_state.distance + position.manhattan_distance(last_state.position) as u16,
+            already_visited,
+        }
+    }
+}
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.distance.cmp(&other.distance) {
+            Ordering::Greater => std::cmp::Ordering::Greater,
+            Ordering::Less => std::cmp::Ordering::Less,
+            Ordering::Equal => {
+                (self.position.y, self.position.x).cmp(&(other.position.y, other.position.x))
+            }
+        }
+    }
+}
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/// Hiking trails map, slopes can only be walked downhill.
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+impl Map {
+    pub fn from_chars(grid: &Grid<char>) -> Self {
+        Self {
+            tiles: grid.map(|c| Tile::from_char(*c)),
+        }
+    }
+    pub fn from_chars_without_slope(grid: &Grid<char>) -> Self {
+        Self {
+            tiles: grid.map(|c| Tile::from_char_without_slope(*c)),
+        }
+    }
+
+    fn dist_index(&self, position: Point2<u16>) -> usize {
+        position.y as usize * self.tiles.width() + position.x as usize
+    }
+
+    pub fn next_states_available(&self, state: &State) -> Vec<State> {
+        let mut states = Vec::new();
+        for dir in [
+            Direction::North,
+            Direction::South,
+            Direction::West,
+            Direction::East,
+        ] {
+            let Some(position) = state.position.step(dir) else {
+                continue;
+            };
+            let Some(tile) = self.tiles.get(position.x as usize, position.y as usize) else {
+                continue;
+            };
+            if state.already_visited.contains(&position) {
+                continue;
+            }
+
+            match tile.tile_type {
+                TileType::Empty => {
+                    states.push(State::new_from_state(position, state));
+                }
+                TileType::Slope if tile.direction == Some(dir) => {
+                    if let Some(position) = position.step(dir) {
+                        states.push(State::new_from_state(position, state));
+                    }
+                }
+                _ => {}
+            }
+        }
+        states
+    }
+
+    pub fn find_longest_hike(&self) -> u16 {
+        let mut dist = vec![u16::MIN; self.tiles.width() * self.tiles.height()];
+        let mut heap = BinaryHeap::new();
+
+        // Add initiale states
+        dist[0] = 0;
+        heap.push(State::new(Point2::new(1, 0), 0));
+
+        while let Some(state) = heap.pop() {
+            if state.distance < dist[self.dist_index(state.position)] {
+                continue;
+            }
+
+            let next_states = self.next_states_available(&state);
+
+            for next_state in next_states.iter() {
+                let index = self.dist_index(next_state.position);
+                if next_state.distance > dist[index] {
+                    dist[index] = next_state.distance;
+                    heap.push(next_state.clone());
+                }
+            }
+        }
+
+        *dist[(self.tiles.height() - 1) * self.tiles.width()..]
+            .iter()
+            .max()
+            .unwrap_or(&0)
+    }
+}
+
+#[derive(Eq, PartialEq, Clone)]
+struct GraphState {
+    state: State,
+    origin: Point2<u16>,
+}
+impl GraphState {
+    pub fn new(origin: Point2<u16>, state: State) -> Self {
+        Self { state, origin }
+    }
+}
+#[derive(Debug, Clone)]
+struct Path {
+    start: Point2<u16>,
+    end: Point2<u16>,
+    weight: u16,
+}
+#[derive(Debug, Clone)]
+struct Node {
+    id: u8,
+    nb_path: u8,
+    paths: [u8; 4],
+}
+impl Node {
+    pub fn new(id: u8) -> Self {
+        Self {
+            id,
+            nb_path: 0,
+            paths: [0; 4],
+        }
+    }
+
+    pub fn add_path(&mut self, path_id: u8) {
+        if self.nb_path == 4 {
+            panic!("Too many path for this node");
+        }
+        self.paths[self.nb_path as usize] = path_id;
+        self.nb_path += 1;
+    }
+}
+/// Trails compressed into a graph of crossroads weighted by the length of the paths between them.
+pub struct GraphMap {
+    paths: Vec<Path>,
+    node_lookup_table: HashMap<Point2<u16>, Node>,
+    end_node_coord: Point2<u16>,
+}
+impl GraphMap {
+    pub fn new(grid: &Grid<char>) -> Self {
+        let map = Map::from_chars_without_slope(grid);
+        let mut paths = Vec::new();
+        let mut node_lookup_table: HashMap<Point2<u16>, Node> = HashMap::new();
+        let mut heap = Vec::new();
+        heap.push(GraphState::new(
+            Point2::new(1, 0),
+            State::new(Point2::new(1, 0), 0),
+        ));
+
+        while let Some(graph_state) = heap.pop() {
+            let state = graph_state.state;
+            let mut previous_state = state.clone();
+            loop {
+                let next_states = map.next_states_available(&previous_state);
+
+                match next_states.len().cmp(&1) {
+                    Ordering::Equal => {
+                        previous_state = next_states.first().unwrap().clone();
+                    }
+                    Ordering::Greater => {
+                        if paths.iter().any(|path: &Path| {
+                            (path.start == graph_state.origin
+                                && path.end == previous_state.position)
+                                || (path.start == previous_state.position
+                                    && path.end == graph_state.origin)
+                        }) {
+                            break;
+                        }
+
+                        // Create path from state to previous_state
+                        let path = Path {
+                            start: graph_state.origin,
+                            end: previous_state.position,
+                            weight: previous_state.distance - state.distance + 1,
+                        };
+                        paths.push(path);
+
+                        let node_lookup_table_last_index =
+                            node_lookup_table.len().wrapping_sub(1) as u8;
+                        node_lookup_table
+                            .entry(graph_state.origin)
+                            .or_insert_with(|| Node::new(node_lookup_table_last_index));
+                        node_lookup_table
+                            .get_mut(&graph_state.origin)
+                            .unwrap()
+                            .add_path((paths.len() - 1) as u8);
+
+                        let node_lookup_table_last_index =
+                            node_lookup_table.len().wrapping_sub(1) as u8;
+                        node_lookup_table
+                            .entry(previous_state.position)
+                            .or_insert_with(|| Node::new(node_lookup_table_last_index));
+                        node_lookup_table
+                            .get_mut(&previous_state.position)
+                            .unwrap()
+                            .add_path((paths.len() - 1) as u8);
+
+                        for next_state in next_states.iter() {
+                            heap.push(GraphState::new(previous_state.position, next_state.clone()));
+                        }
+                        break;
+                    }
+                    Ordering::Less => {
+                        if paths.iter().any(|path: &Path| {
+                            (path.start == graph_state.origin
+                                && path.end == previous_state.position)
+                                || (path.start == previous_state.position
+                                    && path.end == graph_state.origin)
+                        }) {
+                            break;
+                        }
+
+                        if previous_state.position.y as usize == map.tiles.height() - 1 {
+                            let path = Path {
+                                start: graph_state.origin,
+                                end: previous_state.position,
+                                weight: previous_state.distance - state.distance + 1,
+                            };
+                            paths.push(path);
+
+                            if node_lookup_table.contains_key(&graph_state.origin) {
+                                node_lookup_table.insert(
+                                    graph_state.origin,
+                                    Node::new((node_lookup_table.len() - 1) as u8),
+                                );
+                            }
+                            node_lookup_table
+                                .get_mut(&graph_state.origin)
+                                .unwrap()
+                                .add_path((paths.len() - 1) as u8);
+
+                            node_lookup_table.insert(
+                                previous_state.position,
+                                Node::new((node_lookup_table.len() - 1) as u8),
+                            );
+                            node_lookup_table
+                                .get_mut(&previous_state.position)
+                                .unwrap()
+                                .add_path((paths.len() - 1) as u8);
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+
+        Self {
+            paths,
+            node_lookup_table,
+            end_node_coord: Point2::new(
+                (map.tiles.width() - 2) as u16,
+                (map.tiles.height() - 1) as u16,
+            ),
+        }
+    }
+
+    pub fn find_longest_hike(&self) -> u16 {
+        self.find_longest_path_from_coord(0, Point2::new(1, 0), 0) - 1
+    }
+
+    fn find_longest_path_from_coord(
+        &self,
+        already_visited: u64,
+        coord: Point2<u16>,
+        weight: u16,
+    ) -> u16 {
+        let mut states = Vec::new();
+        states.push((already_visited, coord, weight));
+        let end_node = self.node_lookup_table.get(&self.end_node_coord).unwrap();
+
+        let mut longest_path = u16::MIN;
+        while let Some((mut already_visited, coord, weight)) = states.pop() {
+            let node = self.node_lookup_table.get(&coord).unwrap();
+            if end_node.id == node.id {
+                if weight > longest_path {
+                    longest_path = weight;
+                }
+                continue;
+            }
+
+            already_visited |= 1u64.checked_shl(node.id as u32).unwrap_or(0);
+            for index in node.paths[0..node.nb_path as usize].iter() {
+                let path = self.paths.get(*index as usize).unwrap();
+                let next_coord = if path.start == coord {
+                    path.end
+                } else {
+                    path.start
+                };
+
+                let next_node = self.node_lookup_table.get(&next_coord).unwrap();
+                if already_visited & 1u64.checked_shl(next_node.id as u32).unwrap_or(0) == 0 {
+                    states.push((already_visited, next_coord, weight + path.weight));
+                }
+            }
+        }
+        longest_path + self.paths.get(end_node.paths[0] as usize).unwrap().weight
+    }
+}
+
+#[cfg(test)]
+mod tests_day23 {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day23.parse("#.#\n#x#\n#.#").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected character `x`"
+        );
+    }
+
+    #[test]
+    fn test_graph_map() {
+        let input = include_str!("../../aoc-2023-inputs/day-23/test2.txt");
+        let graph_map = GraphMap::new(&Day23.parse(input).unwrap());
+        dbg!(graph_map.paths.to_vec());
+        assert_eq!(graph_map.paths.len(), 5);
+        assert_eq!(graph_map.paths[0].weight, 4);
+        assert_eq!(graph_map.paths.iter().map(|p| p.weight).sum::<u16>(), 20);
+    }
+}
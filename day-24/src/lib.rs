@@ -0,0 +1,322 @@
+use common::{parse_number, Answer, ParseError, Solution};
+use itertools::Itertools;
+use ndarray::prelude::*;
+use ndarray_linalg::*;
+use regex::Regex;
+
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+/// Hailstone trajectory from its starting `point` at velocity `v0`.
+pub struct Path {
+    pub point: (i64, i64, i64),
+    pub v0: (i64, i64, i64),
+}
+impl Path {
+    pub fn new(point: (i64, i64, i64), v0: (i64, i64, i64)) -> Self {
+        Self { point, v0 }
+    }
+
+    /// Returns the coefficient of the path in 2D a,b such as the path is given by y=ax+b
+    pub fn equation_2d(&self) -> (f64, f64) {
+        let p1 = (self.point.0 as f64, self.point.1 as f64);
+        let p2 = (
+            self.point.0 as f64 + self.v0.0 as f64,
+            self.point.1 as f64 + self.v0.1 as f64,
+        );
+
+        let a = (p1.1 - p2.1) / (p1.0 - p2.0);
+        let b = p1.1 - a * p1.0;
+        (a, b)
+    }
+
+    pub fn intersect_2d(&self, p: &Path) -> Option<(f64, f64)> {
+        let (a1, b1) = self.equation_2d();
+        let (a2, b2) = p.equation_2d();
+
+        if a1 - a2 == 0.0 {
+            return None;
+        }
+
+        let x = (b2 - b1) / (a1 - a2);
+        let y = a1 * x + b1;
+        Some((x, y))
+    }
+
+    pub fn is_point_on_2dpath(&self, point: (f64, f64)) -> bool {
+        if self.v0.0 > 0 && (self.point.0 as f64 > point.0)
+            || self.v0.0 < 0 && (self.point.0 as f64) < point.0
+        {
+            return false;
+        }
+        if self.v0.1 > 0 && (self.point.1 as f64) > point.1
+            || self.v0.1 < 0 && (self.point.1 as f64) < point.1
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+pub struct Day24 {
+    /// Lower bound of the test area on both axes in part 1.
+    pub min_box: u64,
+    /// Upper bound of the test area on both axes in part 1.
+    pub max_box: u64,
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Self {
+            min_box: 200000000000000,
+            max_box: 400000000000000,
+        }
+    }
+}
+
+impl Solution for Day24 {
+    type Input = Vec<Path>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(r"^(-?\d+),\s+(-?\d+),\s+(-?\d+)\s+@\s+(-?\d+),\s+(-?\d+),\s+(-?\d+)$")
+            .unwrap();
+        let mut paths = Vec::new();
+        for line in input.lines() {
+            let (_, values) = re.captures(line).map(|c| c.extract()).ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    line,
+                    "expected `<px>, <py>, <pz> @ <vx>, <vy>, <vz>`",
+                )
+            })?;
+            let [px, py, pz, vx, vy, vz] = values.map(|value| parse_number(input, value));
+            paths.push(Path::new((px?, py?, pz?), (vx?, vy?, vz?)));
+        }
+        Ok(paths)
+    }
+
+    fn part_1(&self, paths: &Self::Input) -> Answer {
+        count_intersections(paths, self.min_box, self.max_box).into()
+    }
+
+    fn part_2(&self, paths: &Self::Input) -> Answer {
+        throw_position_sum(paths).into()
+    }
+}
+
+/// Pairs of hailstones whose 2D paths cross inside the test area, in the future.
+pub fn count_intersections(paths: &[Path], min_box: u64, max_box: u64) -> u64 {
+    let mut count = 0;
+    for i in 0..paths.len() {
+        for j in i + 1..paths.len() {
+            if let Some((x, y)) = paths[i].intersect_2d(&paths[j]) {
+                if x >= min_box as f64
+                    && x <= max_box as f64
+                    && y >= min_box as f64
+                    && y <= max_box as f64
+                    && paths[i].is_point_on_2dpath((x, y))
+                    && paths[j].is_point_on_2dpath((x, y))
+                {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+/// Solves the linear system of a rock hitting the three hailstones, its position comes first.
+pub fn solve_system(p1: &Path, p2: &Path, p3: &Path) -> Vec<f64> {
+    let a: Array2<i64> = arr2(&[
+        [
+            0,
+            p2.v0.2 - p1.v0.2,
+            p1.v0.1 - p2.v0.1,
+            0,
+            p1.point.2 - p2.point.2,
+            p2.point.1 - p1.point.1,
+        ],
+        [
+            p1.v0.2 - p2.v0.2,
+            0,
+            p2.v0.0 - p1.v0.0,
+            p2.point.2 - p1.point.2,
+            0,
+            p1.point.0 - p2.point.0,
+        ],
+        [
+            p2.v0.1 - p1.v0.1,
+            p1.v0.0 - p2.v0.0,
+            0,
+            p1.point.1 - p2.point.1,
+            p2.point.0 - p1.point.0,
+            0,
+        ],
+        [
+            0,
+            p3.v0.2 - p1.v0.2,
+            p1.v0.1 - p3.v0.1,
+            0,
+            p1.point.2 - p3.point.2,
+            p3.point.1 - p1.point.1,
+        ],
+        [
+            p1.v0.2 - p3.v0.2,
+            0,
+            p3.v0.0 - p1.v0.0,
+            p3.point.2 - p1.point.2,
+            0,
+            p1.point.0 - p3.point.0,
+        ],
+        [
+            p3.v0.1 - p1.v0.1,
+            p1.v0.0 - p3.v0.0,
+            0,
+            p1.point.1 - p3.point.1,
+            p3.point.0 - p1.point.0,
+            0,
+        ],
+    ]);
+    let a: Array2<f64> = a.mapv(|x| x as f64);
+
+    let b: Array1<i64> = -arr1(&[
+        p1.point.1 * p1.v0.2 - p2.point.1 * p2.v0.2 - p1.point.2 * p1.v0.1 + p2.point.2 * p2.v0.1,
+        p1.point.2 * p1.v0.0 - p2.point.2 * p2.v0.0 - p1.point.0 * p1.v0.2 + p2.point.0 * p2.v0.2,
+        p1.point.0 * p1.v0.1 - p2.point.0 * p2.v0.1 - p1.point.1 * p1.v0.0 + p2.point.1 * p2.v0.0,
+        p1.point.1 * p1.v0.2 - p3.point.1 * p3.v0.2 - p1.point.2 * p1.v0.1 + p3.point.2 * p3.v0.1,
+        p1.point.2 * p1.v0.0 - p3.point.2 * p3.v0.0 - p1.point.0 * p1.v0.2 + p3.point.0 * p3.v0.2,
+        p1.point.0 * p1.v0.1 - p3.point.0 * p3.v0.1 - p1.point.1 * p1.v0.0 + p3.point.1 * p3.v0.0,
+    ]);
+    let b: Array1<f64> = b.mapv(|x| x as f64);
+
+    let x = a.solve(&b).unwrap();
+    x.into_raw_vec()
+}
+
+/// Sum of the coordinates of the rock thrown to hit every hailstone.
+pub fn throw_position_sum(paths: &[Path]) -> u64 {
+    // Float imprecision can make a system's solution slightly off. The velocity is small enough to
+    // be rounded safely, keep the first one from which an exact throw hits every hailstone
+    let exact = paths
+        .iter()
+        .tuple_combinations()
+        .take(100)
+        .find_map(|(p1, p2, p3)| {
+            let r = solve_system(p1, p2, p3);
+            let v0 = (
+                r[3].round() as i64,
+                r[4].round() as i64,
+                r[5].round() as i64,
+            );
+            let point = throw_position(p1, p2, v0)?;
+            hits_every_hailstone(paths, point, v0).then_some(point)
+        });
+    if let Some((x, y, z)) = exact {
+        return (x + y + z) as u64;
+    }
+
+    // Otherwise average the solutions of the first 10 combinations of paths
+    let results = paths
+        .iter()
+        .tuple_combinations()
+        .map(|(p1, p2, p3)| {
+            let r = solve_system(p1, p2, p3);
+            vec![r[0], r[1], r[2]]
+        })
+        .take(10)
+        .collect::<Vec<_>>();
+
+    let mut x = 0.0;
+    let mut y = 0.0;
+    let mut z = 0.0;
+
+    for r in results.iter() {
+        x += r[0];
+        y += r[1];
+        z += r[2];
+    }
+    let xr = (x / results.len() as f64).round() as u64;
+    let yr = (y / results.len() as f64).round() as u64;
+    let zr = (z / results.len() as f64).round() as u64;
+
+    xr + yr + zr
+}
+
+/// Position a rock thrown at velocity `v0` must start from to hit both hailstones, if they are
+/// not parallel from its point of view.
+pub fn throw_position(p1: &Path, p2: &Path, v0: (i64, i64, i64)) -> Option<(i64, i64, i64)> {
+    let point = |p: &Path| [p.point.0 as i128, p.point.1 as i128, p.point.2 as i128];
+    let speed = |p: &Path| {
+        [
+            (p.v0.0 - v0.0) as i128,
+            (p.v0.1 - v0.1) as i128,
+            (p.v0.2 - v0.2) as i128,
+        ]
+    };
+    let (a, u, b, w) = (point(p1), speed(p1), point(p2), speed(p2));
+    // Crossing time of the first hailstone, on the first plane where the paths are not parallel
+    let time = [(0, 1), (0, 2), (1, 2)].iter().find_map(|&(i, j)| {
+        let cross = u[i] * w[j] - u[j] * w[i];
+        let numerator = (b[i] - a[i]) * w[j] - (b[j] - a[j]) * w[i];
+        (cross != 0 && numerator % cross == 0).then(|| numerator / cross)
+    })?;
+    let coordinate = |i: usize| i64::try_from(a[i] + u[i] * time).ok();
+    Some((coordinate(0)?, coordinate(1)?, coordinate(2)?))
+}
+
+/// Whether a rock thrown from `point` at velocity `v0` hits every hailstone at a non-negative
+/// integer time.
+pub fn hits_every_hailstone(paths: &[Path], point: (i64, i64, i64), v0: (i64, i64, i64)) -> bool {
+    paths.iter().all(|path| {
+        let axes = [
+            (point.0 - path.point.0, path.v0.0 - v0.0),
+            (point.1 - path.point.1, path.v0.1 - v0.1),
+            (point.2 - path.point.2, path.v0.2 - v0.2),
+        ];
+        let time = match axes.iter().find(|(_, speed)| *speed != 0) {
+            Some((distance, speed)) if distance % speed == 0 => distance / speed,
+            Some(_) => return false,
+            None => 0,
+        };
+        time >= 0
+            && axes
+                .iter()
+                .all(|(distance, speed)| *distance as i128 == *speed as i128 * time as i128)
+    })
+}
+
+#[cfg(test)]
+mod tests_day24 {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day24::default().parse("19, 13, 30 @ -2,  1, -2\n18, 19 @ -1, -1, -2");
+        assert_eq!(
+            error.err().unwrap().to_string(),
+            "line 2, column 1: expected `<px>, <py>, <pz> @ <vx>, <vy>, <vz>`"
+        );
+    }
+
+    #[test]
+    fn test_reference_part_2() {
+        let input = include_str!("../../aoc-2023-inputs/day-24/test.txt");
+        let paths = Day24::default().parse(input).unwrap();
+        assert_eq!(reference::part_2(&paths), 47);
+    }
+
+    /// Generated hailstorm (`aoc gen 24 --seed 11 --size 4`) where the average of the float
+    /// solutions is off by 10.
+    #[test]
+    fn test_throw_position_sum_exact() {
+        let input = "376278853140426, -96758119128267, 386221356838211 @ -240, 168, -96
+-25274206427872, 179518911430292, 436289198379188 @ 275, -202, -148
+131501993028520, 53005770014443, 228820533391127 @ 247, 283, 272
+324735541149132, -43554644300280, 111603470381545 @ -153, 66, 247
+";
+        let paths = Day24::default().parse(input).unwrap();
+        assert_eq!(throw_position_sum(&paths), 664995566013892);
+    }
+}
@@ -0,0 +1,95 @@
+//! Slow but straightforward solution, to check [`Day24`](crate::Day24) against.
+
+use crate::Path;
+
+/// Fastest rock speed tried on each axis before giving up.
+const MAX_SPEED: i128 = 1000;
+
+/// Sum of the coordinates of the rock, trying every rock velocity by increasing speed until one
+/// hits every hailstone at a non-negative integer time. Only uses integer arithmetic.
+pub fn part_2(paths: &[Path]) -> u64 {
+    for speed in 0..=MAX_SPEED {
+        for vx in -speed..=speed {
+            let vys = if vx.abs() == speed {
+                (-speed..=speed).collect()
+            } else {
+                vec![-speed, speed]
+            };
+            for vy in vys {
+                if let Some((x, y, z)) = throw(paths, vx, vy) {
+                    return (x + y + z) as u64;
+                }
+            }
+        }
+    }
+    panic!("no rock thrown at a speed up to {MAX_SPEED} hits every hailstone");
+}
+
+/// Position of the rock thrown at velocity `(vx, vy, _)` that hits every hailstone, if any.
+fn throw(paths: &[Path], vx: i128, vy: i128) -> Option<(i128, i128, i128)> {
+    // Seen from the rock, hailstones move at their velocity minus the rock's one and must all
+    // go through the rock's position
+    let stones: Vec<_> = paths
+        .iter()
+        .map(|path| {
+            let (px, py, pz) = path.point;
+            let (hx, hy, hz) = path.v0;
+            (
+                (px as i128, py as i128, pz as i128),
+                (hx as i128 - vx, hy as i128 - vy, hz as i128),
+            )
+        })
+        .collect();
+
+    // Rock position on the plane, where the first two non parallel hailstones cross
+    let (x, y) = stones.iter().enumerate().find_map(|(i, (pa, ua))| {
+        stones[i + 1..].iter().find_map(|(pb, ub)| {
+            let cross = ua.0 * ub.1 - ua.1 * ub.0;
+            if cross == 0 {
+                return None;
+            }
+            let time = exact_div((pb.0 - pa.0) * ub.1 - (pb.1 - pa.1) * ub.0, cross)?;
+            (time >= 0).then_some((pa.0 + ua.0 * time, pa.1 + ua.1 * time))
+        })
+    })?;
+
+    // Time each hailstone is hit, unknown when it is still relative to the rock on the plane
+    let mut times = Vec::new();
+    for ((px, py, _), (ux, uy, _)) in stones.iter() {
+        let time = match (*ux, *uy) {
+            (0, 0) => None,
+            (0, uy) => Some(exact_div(y - py, uy)?),
+            (ux, _) => Some(exact_div(x - px, ux)?),
+        };
+        let t = time.unwrap_or(0);
+        if t < 0 || px + ux * t != x || py + uy * t != y {
+            return None;
+        }
+        times.push(time);
+    }
+
+    // Rock height and vertical velocity from two hailstones hit at different times
+    let hits: Vec<(i128, i128)> = stones
+        .iter()
+        .zip(times.iter())
+        .filter_map(|((p, u), time)| time.map(|time| (time, p.2 + u.2 * time)))
+        .collect();
+    let &(ta, za) = hits.first()?;
+    let &(tb, zb) = hits.iter().find(|(time, _)| *time != ta)?;
+    let vz = exact_div(za - zb, ta - tb)?;
+    let z = za - vz * ta;
+
+    let all_hit = stones.iter().zip(times.iter()).all(|((p, u), time)| {
+        let time = match time {
+            Some(time) => Some(*time),
+            None if u.2 == vz => (p.2 == z).then_some(0),
+            None => exact_div(p.2 - z, vz - u.2),
+        };
+        time.is_some_and(|time| time >= 0 && p.2 + u.2 * time == z + vz * time)
+    });
+    all_hit.then_some((x, y, z))
+}
+
+fn exact_div(a: i128, b: i128) -> Option<i128> {
+    (b != 0 && a % b == 0).then(|| a / b)
+}
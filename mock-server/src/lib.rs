@@ -0,0 +1,283 @@
+//! Offline stand-in for the puzzle website: serves the inputs and checks the answers submitted
+//! over plain HTTP, with the same pages as the real site.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+pub const YEAR: u16 = 2023;
+
+pub struct MockServer {
+    session: String,
+    inputs: HashMap<u8, String>,
+    answers: HashMap<(u8, u8), String>,
+    cooldown: Duration,
+    solved: HashSet<(u8, u8)>,
+    wait_until: Option<Instant>,
+}
+
+impl MockServer {
+    /// Server only answering requests with the `session` cookie.
+    pub fn new(session: &str) -> Self {
+        Self {
+            session: session.to_string(),
+            inputs: HashMap::new(),
+            answers: HashMap::new(),
+            cooldown: Duration::from_secs(60),
+            solved: HashSet::new(),
+            wait_until: None,
+        }
+    }
+
+    pub fn with_input(mut self, day: u8, input: &str) -> Self {
+        self.inputs.insert(day, input.to_string());
+        self
+    }
+
+    pub fn with_answer(mut self, day: u8, part: u8, answer: &str) -> Self {
+        self.answers.insert((day, part), answer.to_string());
+        self
+    }
+
+    /// Time to wait after a wrong answer before submitting again (default: one minute).
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Serves on a random local port from a background thread, returns its address.
+    pub fn spawn(self) -> std::io::Result<SocketAddr> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        std::thread::spawn(move || self.serve(listener));
+        Ok(address)
+    }
+
+    /// Handles the connections one at a time, forever.
+    pub fn serve(mut self, listener: TcpListener) {
+        for stream in listener.incoming().flatten() {
+            if let Err(err) = self.handle(stream) {
+                eprintln!("mock-server: {err}");
+            }
+        }
+    }
+
+    fn handle(&mut self, stream: TcpStream) -> std::io::Result<()> {
+        let mut reader = BufReader::new(stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut headers = HashMap::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line)?;
+            let Some((name, value)) = line.trim_end().split_once(':') else {
+                break;
+            };
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+        let length = headers
+            .get("content-length")
+            .and_then(|length| length.parse().ok())
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+
+        let mut words = request_line.split_whitespace();
+        let (method, path) = (words.next().unwrap_or(""), words.next().unwrap_or(""));
+        let session = headers.get("cookie").and_then(|cookie| {
+            cookie
+                .split(';')
+                .find_map(|pair| pair.trim().strip_prefix("session="))
+        });
+        let (status, body) = if session != Some(self.session.as_str()) {
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                    .to_string(),
+            )
+        } else {
+            self.respond(method, path, &String::from_utf8_lossy(&body))
+        };
+
+        let reason = match status {
+            200 => "OK",
+            400 => "Bad Request",
+            _ => "Not Found",
+        };
+        let mut stream = reader.into_inner();
+        write!(
+            stream,
+            "HTTP/1.1 {status} {reason}\r\nContent-Type: text/html; charset=utf-8\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )?;
+        stream.flush()
+    }
+
+    fn respond(&mut self, method: &str, path: &str, body: &str) -> (u16, String) {
+        let not_found = (404, "404 Not Found\n".to_string());
+        let Some(rest) = path.strip_prefix(&format!("/{YEAR}/day/")) else {
+            return not_found;
+        };
+        let Some((day, page)) = rest.split_once('/') else {
+            return not_found;
+        };
+        let Ok(day) = day.parse::<u8>() else {
+            return not_found;
+        };
+        match (method, page) {
+            ("GET", "input") => match self.inputs.get(&day) {
+                Some(input) => (200, input.clone()),
+                None => not_found,
+            },
+            ("POST", "answer") => {
+                let form: HashMap<&str, String> = body
+                    .split('&')
+                    .filter_map(|pair| pair.split_once('='))
+                    .map(|(key, value)| (key, decode_form_value(value)))
+                    .collect();
+                let level = form.get("level").and_then(|level| level.parse().ok());
+                match (level, form.get("answer")) {
+                    (Some(level), Some(answer)) => (200, self.submit(day, level, answer)),
+                    _ => (400, "Bad Request\n".to_string()),
+                }
+            }
+            _ => not_found,
+        }
+    }
+
+    fn submit(&mut self, day: u8, level: u8, answer: &str) -> String {
+        let back = format!("[<a href=\"/{YEAR}/day/{day}\">Return to Day {day}</a>]");
+        let page = |message: String| {
+            format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>\n")
+        };
+        let right_level = match level {
+            1 => !self.solved.contains(&(day, 1)),
+            2 => self.solved.contains(&(day, 1)) && !self.solved.contains(&(day, 2)),
+            _ => false,
+        };
+        let Some(expected) = self.answers.get(&(day, level)).filter(|_| right_level) else {
+            return page(format!(
+                "You don't seem to be solving the right level.  Did you already complete it? {back}"
+            ));
+        };
+
+        if let Some(left) = self
+            .wait_until
+            .and_then(|until| until.checked_duration_since(Instant::now()))
+        {
+            let seconds = left.as_secs() + 1;
+            let left = match seconds {
+                0..=59 => format!("{seconds}s"),
+                _ => format!("{}m {}s", seconds / 60, seconds % 60),
+            };
+            return page(format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {left} left to wait. {back}"
+            ));
+        }
+
+        if answer.trim() == expected {
+            self.solved.insert((day, level));
+            return page(format!(
+                "That's the right answer!  You are one gold star closer to restoring snow \
+                 operations. {back}"
+            ));
+        }
+        self.wait_until = Some(Instant::now() + self.cooldown);
+        let hint = match (answer.trim().parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+            (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+            _ => "",
+        };
+        page(format!(
+            "That's not the right answer{hint}.  If you're stuck, make sure you're using the full \
+             input data.  Please wait one minute before trying again. {back}"
+        ))
+    }
+}
+
+/// Decodes an `application/x-www-form-urlencoded` value.
+fn decode_form_value(value: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut chars = value.bytes();
+    while let Some(byte) = chars.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = chars.by_ref().take(2).collect();
+                let decoded = std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                bytes.push(decoded.unwrap_or(b'?'));
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests_mock_server {
+    use super::*;
+
+    fn request(address: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_input() {
+        let address = MockServer::new("abc")
+            .with_input(3, "467..114..\n")
+            .spawn()
+            .unwrap();
+        let response = request(
+            address,
+            "GET /2023/day/3/input HTTP/1.1\r\nCookie: session=abc\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with("\r\n\r\n467..114..\n"));
+
+        let response = request(
+            address,
+            "GET /2023/day/3/input HTTP/1.1\r\nCookie: session=xyz\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+        let response = request(
+            address,
+            "GET /2023/day/4/input HTTP/1.1\r\nCookie: session=abc\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let mut server = MockServer::new("abc")
+            .with_answer(1, 1, "142")
+            .with_answer(1, 2, "two words")
+            .with_cooldown(Duration::ZERO);
+        assert!(server.submit(1, 2, "1").contains("right level"));
+        assert!(server.submit(1, 1, "200").contains("too high"));
+        assert!(server.submit(1, 1, "100").contains("too low"));
+        assert!(server.submit(1, 1, "142").contains("the right answer!"));
+        assert!(server.submit(1, 1, "142").contains("right level"));
+        assert!(server
+            .submit(1, 2, "two")
+            .contains("That's not the right answer."));
+        assert!(server
+            .submit(1, 2, "two words")
+            .contains("the right answer!"));
+
+        let mut server = MockServer::new("abc").with_answer(2, 1, "8");
+        server.submit(2, 1, "9");
+        assert!(server
+            .submit(2, 1, "8")
+            .contains("You have 1m 0s left to wait."));
+        assert_eq!(decode_form_value("two+words%21"), "two words!");
+    }
+}
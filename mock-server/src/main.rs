@@ -0,0 +1,82 @@
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use common::Answers;
+use mock_server::MockServer;
+
+const USAGE: &str = "Usage:
+    mock-server [--port N] [--session TOKEN] [--inputs DIR] [--cooldown SECS]
+
+Serves `DIR/day-XX/input.txt` and checks the answers against `DIR/answers.toml`, point the
+runner at it with `AOC_URL=http://127.0.0.1:<port>`.
+
+Options:
+    --port N            Port to listen on (default: 8080)
+    --session TOKEN     Session cookie expected from clients (default: `mock`)
+    --inputs DIR        Directory of the inputs (default: aoc-2023-inputs)
+    --cooldown SECS     Time to wait after a wrong answer (default: 60)";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            eprintln!("Run `mock-server --help` for usage.");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut port = 8080;
+    let mut session = "mock".to_string();
+    let mut inputs = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc-2023-inputs"));
+    let mut cooldown = Duration::from_secs(60);
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for `{arg}`"));
+        match arg.as_str() {
+            "--port" => {
+                let value = value()?;
+                port = value
+                    .parse()
+                    .map_err(|_| format!("invalid port `{value}`"))?;
+            }
+            "--session" => session = value()?,
+            "--inputs" => inputs = PathBuf::from(value()?),
+            "--cooldown" => {
+                let value = value()?;
+                let seconds = value
+                    .parse()
+                    .map_err(|_| format!("invalid cooldown `{value}`"))?;
+                cooldown = Duration::from_secs(seconds);
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    let mut server = MockServer::new(&session).with_cooldown(cooldown);
+    for day in 1..=25 {
+        let path = inputs.join(format!("day-{day:02}")).join("input.txt");
+        if let Ok(input) = std::fs::read_to_string(path) {
+            server = server.with_input(day, &input);
+        }
+    }
+    let answers = Answers::load(&inputs.join("answers.toml"))?;
+    for (day, part, answer) in answers.iter() {
+        server = server.with_answer(day, part, &answer.to_string());
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|err| format!("could not listen on port {port}: {err}"))?;
+    println!("Listening on http://127.0.0.1:{port}");
+    server.serve(listener);
+    Ok(())
+}
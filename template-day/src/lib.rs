@@ -0,0 +1,20 @@
+use common::{Answer, ParseError, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        todo!()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        todo!()
+    }
+}
+
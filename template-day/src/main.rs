@@ -1,32 +1,15 @@
-fn main() {
-    let input = include_str!("./input.txt");
-    let now = std::time::Instant::now();
-    dbg!(part_1(input));
-    dbg!(part_2(input));
-    println!("Time: {:?}", now.elapsed());
-}
+use common::{read_input, Solution};
+use day_{{day}}::Day{{day}};
 
-fn part_1(input: &str) -> u64 {
-    todo!()
-}
-
-fn part_2(input: &str) -> u64 {
-    todo!()
-}
-
-#[cfg(test)]
-mod tests_day{{day}} {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = include_str!("./test.txt");
-        assert_eq!(part_1(input), 0);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = include_str!("./test.txt");
-        assert_eq!(part_2(input), 0);
-    }
+fn main() {
+    let input = read_input(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../aoc-2023-inputs/day-{{day}}/input.txt"
+    ))
+    .expect("input file not found, use `aoc run {{day}} --input PATH` instead");
+    let input = Day{{day}}
+        .parse(&input)
+        .unwrap_or_else(|err| panic!("invalid input: {}", err.diagnostic(&input)));
+    dbg!(Day{{day}}.part_1(&input));
+    dbg!(Day{{day}}.part_2(&input));
 }